@@ -0,0 +1,94 @@
+//! Minimal command line parsing: positional arguments, `--name value` options and `--name` switches.
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+/// The arguments of one subcommand.
+#[derive(Debug, Default)]
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+    switches: HashSet<String>,
+    /// The options the subcommand accepts, given or not.
+    accepted: Vec<String>,
+}
+
+impl Args {
+    /// Parse `args`, accepting only the named `options` (which take a value, as `--name value` or `--name=value`)
+    /// and `switches` (which do not).
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        options: &[&str],
+        switches: &[&str],
+    ) -> Result<Args, String> {
+        let mut parsed = Args { accepted: options.iter().map(|name| name.to_string()).collect(), ..Args::default() };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                parsed.positional.push(arg);
+                continue;
+            };
+
+            let (name, inline) = match name.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (name, None),
+            };
+            if switches.contains(&name) && inline.is_none() {
+                parsed.switches.insert(name.to_string());
+            } else if options.contains(&name) {
+                let value = match inline {
+                    Some(value) => value,
+                    None => args.next().ok_or_else(|| format!("--{name} needs a value"))?,
+                };
+                parsed.options.insert(name.to_string(), value);
+            } else {
+                return Err(format!("unknown option --{name}"));
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Fill in the options which were not given from `defaults`, so that flags override a config file. A default
+    /// for an option the subcommand does not accept is an error rather than silently unused.
+    pub fn with_defaults(mut self, defaults: &HashMap<String, String>) -> Result<Args, String> {
+        let mut names: Vec<&String> = defaults.keys().collect();
+        names.sort();
+        if let Some(name) = names.into_iter().find(|name| !self.accepted.contains(name)) {
+            return Err(format!(
+                "the config sets {name}, which this command does not take; set it in a table for the commands which do"
+            ));
+        }
+        for (name, value) in defaults {
+            self.options.entry(name.clone()).or_insert_with(|| value.clone());
+        }
+        Ok(self)
+    }
+
+    /// The positional argument at `index`, if given.
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positional.get(index).map(String::as_str)
+    }
+
+    /// Number of positional arguments.
+    pub fn positional_count(&self) -> usize {
+        self.positional.len()
+    }
+
+    /// The value of option `--name`, parsed, or `None` if it was not given.
+    pub fn value<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        self.options
+            .get(name)
+            .map(|value| value.parse().map_err(|_| format!("invalid value {value:?} for --{name}")))
+            .transpose()
+    }
+
+    /// The value of option `--name`, which must be given.
+    pub fn required<T: FromStr>(&self, name: &str) -> Result<T, String> {
+        self.value(name)?.ok_or_else(|| format!("missing required option --{name}"))
+    }
+
+    /// Whether switch `--name` was given.
+    pub fn switch(&self, name: &str) -> bool {
+        self.switches.contains(name)
+    }
+}
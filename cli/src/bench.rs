@@ -0,0 +1,71 @@
+//! The `bench` command: time every search algorithm on one range.
+
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use palindrome_products::parallel::par_palindrome_products;
+use palindrome_products::{palindrome_products_with, Palindrome, Strategy};
+
+use crate::args::Args;
+use crate::table::write_table;
+use crate::Defaults;
+
+/// One algorithm to time: a strategy, optionally run on every core.
+#[derive(Clone, Copy)]
+struct Algorithm {
+    strategy: Strategy,
+    parallel: bool,
+}
+
+impl Algorithm {
+    /// Every available algorithm. Only the product-first strategy has a parallel implementation.
+    const ALL: [Algorithm; 3] = [
+        Algorithm { strategy: Strategy::ProductFirst, parallel: false },
+        Algorithm { strategy: Strategy::ProductFirst, parallel: true },
+        Algorithm { strategy: Strategy::PalindromeFirst, parallel: false },
+    ];
+
+    fn name(self) -> String {
+        match self.parallel {
+            true => format!("{} (parallel)", self.strategy),
+            false => self.strategy.to_string(),
+        }
+    }
+
+    fn search(self, min: u64, max: u64) -> Option<(Palindrome, Palindrome)> {
+        match self.parallel {
+            true => par_palindrome_products(min, max),
+            false => palindrome_products_with(min, max, self.strategy),
+        }
+    }
+}
+
+pub fn bench(args: impl Iterator<Item = String>, defaults: &Defaults, out: &mut impl Write) -> Result<(), String> {
+    let args = Args::parse(args, &["min", "max", "strategy", "runs"], &[])?.with_defaults(defaults)?;
+    let (min, max): (u64, u64) = (args.required("min")?, args.required("max")?);
+    let only: Option<Strategy> = args.value("strategy")?;
+    let runs: u32 = args.value("runs")?.unwrap_or(3);
+    if runs == 0 {
+        return Err("--runs must be at least 1".to_string());
+    }
+
+    let show = |p: Option<Palindrome>| p.map_or("-".to_string(), |p| p.to_string());
+    let rows: Vec<[String; 4]> = Algorithm::ALL
+        .into_iter()
+        .filter(|algorithm| only.is_none_or(|strategy| strategy == algorithm.strategy))
+        .map(|algorithm| {
+            let mut fastest = Duration::MAX;
+            let mut result = None;
+            for _ in 0..runs {
+                let start = Instant::now();
+                result = algorithm.search(min, max);
+                fastest = fastest.min(start.elapsed());
+            }
+            let (smallest, largest) = result.unzip();
+            [algorithm.name(), show(smallest), show(largest), format!("{fastest:.3?}")]
+        })
+        .collect();
+
+    writeln!(out, "factors {min}..={max}, fastest of {runs} run(s)").map_err(|e| e.to_string())?;
+    write_table(out, ["algorithm", "smallest", "largest", "fastest"], &rows)
+}
@@ -0,0 +1,91 @@
+//! Defaults for the options of every command, read from a TOML file.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+/// The file read from the working directory when no `--config` is given.
+pub const DEFAULT_PATH: &str = "palindrome-products.toml";
+
+/// The keys a config file may set, each the default of the option of the same name.
+const KEYS: [&str; 6] = ["min", "max", "base", "format", "threads", "strategy"];
+
+/// The commands which read defaults from the config, each of which may have a table of its own.
+const COMMANDS: [&str; 4] = ["find", "check", "list", "bench"];
+
+/// Option defaults from a config file: those at the top level for every command, and those in a `[command]` table
+/// for that command only, taking precedence.
+#[derive(Debug, Default)]
+pub struct Config {
+    shared: HashMap<String, String>,
+    commands: HashMap<String, HashMap<String, String>>,
+}
+
+impl Config {
+    /// The defaults for `command`, as they would be written after `--name`.
+    pub fn defaults(&self, command: &str) -> HashMap<String, String> {
+        let mut defaults = self.shared.clone();
+        defaults.extend(self.commands.get(command).into_iter().flatten().map(|(k, v)| (k.clone(), v.clone())));
+        defaults
+    }
+}
+
+/// Parse the config in `text`.
+pub fn parse(text: &str) -> Result<Config, String> {
+    let table: toml::Table = text.parse().map_err(|e: toml::de::Error| e.message().to_string())?;
+    let mut config = Config::default();
+    for (key, value) in table {
+        match value {
+            toml::Value::Table(table) if COMMANDS.contains(&key.as_str()) => {
+                let defaults = table.into_iter().map(|(key, value)| option(key, value)).collect::<Result<_, _>>()?;
+                config.commands.insert(key, defaults);
+            }
+            value => {
+                let (key, value) = option(key, value)?;
+                config.shared.insert(key, value);
+            }
+        }
+    }
+    Ok(config)
+}
+
+/// Check one `key = value` line of the config and turn the value into option text.
+fn option(key: String, value: toml::Value) -> Result<(String, String), String> {
+    if !KEYS.contains(&key.as_str()) {
+        return Err(format!(
+            "unknown key {key:?}; expected one of {} or a table named after one of {}",
+            KEYS.join(", "),
+            COMMANDS.join(", ")
+        ));
+    }
+    let value = match value {
+        toml::Value::Integer(n) => n.to_string(),
+        toml::Value::String(s) => s,
+        other => return Err(format!("{key} must be an integer or a string, not {}", other.type_str())),
+    };
+    Ok((key, value))
+}
+
+/// Split `--config PATH` or `--config=PATH` off `args` and load that file, or else [`DEFAULT_PATH`] if it exists,
+/// returning the other arguments and the config. No file at all means no defaults.
+pub fn load(args: Vec<String>) -> Result<(Vec<String>, Config), String> {
+    let (mut path, mut rest) = (None, Vec::new());
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--config=") {
+            path = Some(value.to_string());
+        } else if arg == "--config" {
+            path = Some(args.next().ok_or("--config needs a value")?);
+        } else {
+            rest.push(arg);
+        }
+    }
+
+    let path = match path {
+        Some(path) => path,
+        None if Path::new(DEFAULT_PATH).is_file() => DEFAULT_PATH.to_string(),
+        None => return Ok((rest, Config::default())),
+    };
+    let text = std::fs::read_to_string(&path).map_err(|e| format!("cannot read {path}: {e}"))?;
+    let config = parse(&text).map_err(|e| format!("invalid config {path}: {e}"))?;
+    Ok((rest, config))
+}
@@ -0,0 +1,339 @@
+//! Command line interface to the palindrome product search.
+
+mod args;
+mod bench;
+mod config;
+mod merge;
+mod output;
+mod progress;
+mod repl;
+mod sweep;
+mod table;
+
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+use std::process::ExitCode;
+
+use palindrome_products::parallel::par_palindrome_products_with;
+use palindrome_products::{factor_pairs, palindrome_products_with, Palindrome, Search, SortedProducts, Strategy};
+
+use args::Args;
+use config::Config;
+use indicatif::ProgressBar;
+use output::Format;
+use serde_json::json;
+
+const USAGE: &str = "\
+usage:
+    palindrome-products find --min N --max N [--strategy S] [--base B] [--format F]
+            [--threads N] [--parallel] [--progress]
+        print the smallest and largest palindromic products of factors within N..=N,
+        searching with strategy S (product-first or palindrome-first) if given, or
+        else with the one benchmarked fastest for the range; the search runs on N
+        threads with --threads N, on every CPU with --parallel, and on every CPU by
+        default for ranges of 100000 factors or more, with the same answer either way;
+        --progress searches on one thread, showing a progress bar with an ETA on
+        standard error
+    palindrome-products list --min N --max N [--strategy S] [--base B] [--format F]
+            [--limit N] [--from-largest] [--progress]
+        print every palindromic product of factors within N..=N with its factor pairs,
+        in ascending order, or descending with --from-largest, by walking the products
+        or the palindromes as S says; --limit N stops the walk after N products, and
+        --progress shows a progress bar on standard error
+    palindrome-products check VALUE [--min N] [--max N] [--base B] [--format F]
+        report whether VALUE is a palindrome and list its factor pairs within the range
+        (defaults to 1..=VALUE)
+    palindrome-products bench --min N --max N [--strategy S] [--runs N]
+        time every search algorithm on the range, or only those of strategy S
+        (product-first or palindrome-first), reporting the fastest of 3 runs
+    palindrome-products merge FILE...
+        combine shard results stored as JSON, one per FILE, into the extremes of the whole
+        range, naming the file and shard each extreme came from
+    palindrome-products sweep --digits A..=B
+        search the factors with n digits for each n within A..=B, printing the
+        extremes, the factors of the largest and the time taken for each
+    palindrome-products repl
+        read commands from standard input, keeping the range and its results
+        between them; type help for the commands
+
+find, list and check take palindromes in base B (2 to 36, 10 by default), which only the
+sequential product-first search supports for other bases, and write format F: text by
+default or json, one object per line for list.
+
+The progress bars count the palindromes a palindrome-first walk visits, or the distinct
+products a product-first walk visits; the latter are counted while the walk goes on, so
+their length and ETA appear once the count is in.
+
+find, check, list and bench read the defaults of their options from palindrome-products.toml
+in the working directory, or from the file given by --config PATH; options on the command
+line take precedence. The file may set min, max, base, format, threads and strategy, at the
+top level for every command or in a table for one command, as in
+    min = 100
+    max = 999
+    [find]
+    threads = 4
+    strategy = \"palindrome-first\"
+A command which does not take an option the top level sets reports an error.";
+
+fn main() -> ExitCode {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let result = config::load(std::env::args().skip(1).collect()).and_then(|(args, config)| {
+        let mut args = args.into_iter();
+        run(args.next(), args, &config, &mut out)
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Run `command` with its arguments, filling in the options they lack from `config`.
+fn run(
+    command: Option<String>,
+    args: impl Iterator<Item = String>,
+    config: &Config,
+    out: &mut impl Write,
+) -> Result<(), String> {
+    let defaults = &config.defaults(command.as_deref().unwrap_or_default());
+    match command.as_deref() {
+        Some("find") => find(args, defaults, out),
+        Some("check") => check(args, defaults, out),
+        Some("list") => list(args, defaults, out),
+        Some("bench") => bench::bench(args, defaults, out),
+        Some("merge") => merge::merge(args, out),
+        Some("sweep") => sweep::sweep(args, out),
+        Some("repl") => repl::repl(io::stdin().lock(), io::stdin().is_terminal(), out),
+        Some("help" | "--help" | "-h") => writeln!(out, "{USAGE}").map_err(|e| e.to_string()),
+        Some(other) => Err(format!("unknown command {other:?}")),
+        None => Err("missing command".to_string()),
+    }
+}
+
+/// Option values to use when the command line does not give them, from the config file.
+type Defaults = HashMap<String, String>;
+
+/// Render factor pairs as `a x b, c x d`.
+fn format_pairs(pairs: &[(u64, u64)]) -> String {
+    pairs
+        .iter()
+        .map(|(a, b)| format!("{a} x {b}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn find(args: impl Iterator<Item = String>, defaults: &Defaults, out: &mut impl Write) -> Result<(), String> {
+    let args = Args::parse(args, &["min", "max", "strategy", "base", "format", "threads"], &["parallel", "progress"])?
+        .with_defaults(defaults)?;
+    let (min, max): (u64, u64) = (args.required("min")?, args.required("max")?);
+    let strategy: Option<Strategy> = args.value("strategy")?;
+    let (base, format) = (output::base(&args)?, output::format(&args)?);
+    let threads: Option<usize> = args.value("threads")?;
+    let (parallel, progress) = (args.switch("parallel"), args.switch("progress"));
+    if threads == Some(0) {
+        return Err("--threads must be at least 1".to_string());
+    }
+    // more than one thread asks for the parallel search just as --parallel does
+    let many_threads = parallel || threads.is_some_and(|threads| threads > 1);
+    if base != 10 && (strategy == Some(Strategy::PalindromeFirst) || many_threads) {
+        return Err(format!("only the sequential product-first search finds palindromes in base {base}"));
+    }
+    if progress && many_threads {
+        eprintln!("warning: --progress searches on one thread, so --parallel and --threads have no effect");
+    }
+
+    let strategy = match base {
+        10 => strategy.unwrap_or_else(|| Strategy::for_range(min, max)),
+        _ => Strategy::ProductFirst,
+    };
+    let products = match (progress, strategy, base) {
+        (true, Strategy::PalindromeFirst, _) => {
+            let bar = progress::palindrome_bar(min, max);
+            let search = Search::new(min, max).strategy(Strategy::PalindromeFirst);
+            let result = search.run_observed(&mut progress::BarObserver(bar.clone())).map_err(|e| e.to_string())?;
+            bar.finish_and_clear();
+            result.smallest().zip(result.largest()).map(|(s, l)| (s.into_inner(), l.into_inner()))
+        }
+        (true, Strategy::ProductFirst, _) => {
+            let bar = progress::product_bar(min, max);
+            let products = extremes_in_base(min, max, base, &bar);
+            bar.finish_and_clear();
+            products
+        }
+        (false, _, 10) => extremes(min, max, strategy, threads, parallel)
+            .map_err(|e| e.to_string())?
+            .map(|(s, l)| (s.into_inner(), l.into_inner())),
+        (false, _, _) => extremes_in_base(min, max, base, &ProgressBar::hidden()),
+    };
+
+    match format {
+        Format::Text => match products {
+            Some((smallest, largest)) => writeln!(
+                out,
+                "smallest: {} = {}\nlargest: {} = {}",
+                output::show(smallest, base),
+                format_pairs(&factor_pairs(smallest, min, max).collect::<Vec<_>>()),
+                output::show(largest, base),
+                format_pairs(&factor_pairs(largest, min, max).collect::<Vec<_>>()),
+            ),
+            None => writeln!(out, "no palindromic products of factors within {min}..={max}"),
+        },
+        Format::Json => {
+            let (smallest, largest) = products.unzip();
+            let pairs = |p: u64| factor_pairs(p, min, max).collect::<Vec<_>>();
+            let product = |p: Option<u64>| p.map(|p| output::product_json(p, &pairs(p)));
+            let value = json!({ "min": min, "max": max, "base": base, "smallest": product(smallest),
+                "largest": product(largest) });
+            writeln!(out, "{value}")
+        }
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// Ranges with at least this many factors are searched in parallel when there are several CPUs, unless `--threads 1`
+/// says otherwise. Their sequential search takes a millisecond or more, several times as long as starting the threads.
+const PARALLEL_FACTORS: u128 = 100_000;
+
+/// The extremes of `min..=max` with `strategy`, searched on `threads` threads if given, on every CPU with `parallel`,
+/// and otherwise on every CPU for ranges of [`PARALLEL_FACTORS`] or more. The answer is the same either way.
+fn extremes(
+    min: u64,
+    max: u64,
+    strategy: Strategy,
+    threads: Option<usize>,
+    parallel: bool,
+) -> Result<Option<(Palindrome, Palindrome)>, rayon::ThreadPoolBuildError> {
+    let wide = (max as u128 + 1).saturating_sub(min as u128) >= PARALLEL_FACTORS;
+    let several_cpus = || std::thread::available_parallelism().is_ok_and(|cpus| cpus.get() > 1);
+    let parallel = match threads {
+        Some(threads) => threads > 1,
+        None => parallel || (wide && several_cpus()),
+    };
+    match parallel {
+        true => Ok(rayon::ThreadPoolBuilder::new()
+            .num_threads(threads.unwrap_or(0))
+            .build()?
+            .install(|| par_palindrome_products_with(min, max, strategy))),
+        false => Ok(palindrome_products_with(min, max, strategy)),
+    }
+}
+
+/// The smallest and largest products of factors within `min..=max` which are palindromes in `base`, walking the
+/// distinct products up from the smallest and down from the largest while advancing `bar` for each.
+fn extremes_in_base(min: u64, max: u64, base: u32, bar: &ProgressBar) -> Option<(u64, u64)> {
+    let check = |product: u64| {
+        bar.inc(1);
+        output::is_palindrome_in(product, base).then_some(product)
+    };
+    // the walk down stops at the smallest palindrome at the latest, so no product is visited twice
+    let smallest = SortedProducts::new(min, max).find_map(check)?;
+    let largest = SortedProducts::new(min, max).rev().take_while(|&product| product >= smallest).find_map(check)?;
+    Some((smallest, largest))
+}
+
+fn list(args: impl Iterator<Item = String>, defaults: &Defaults, out: &mut impl Write) -> Result<(), String> {
+    let options = ["min", "max", "strategy", "base", "format", "limit"];
+    let args = Args::parse(args, &options, &["progress", "from-largest"])?.with_defaults(defaults)?;
+    let (min, max): (u64, u64) = (args.required("min")?, args.required("max")?);
+    let (base, format) = (output::base(&args)?, output::format(&args)?);
+    let limit: Option<usize> = args.value("limit")?;
+    if limit == Some(0) {
+        return Err("--limit must be at least 1".to_string());
+    }
+    let strategy = match base {
+        10 => args.value("strategy")?.unwrap_or_else(|| Strategy::for_range(min, max)),
+        _ => args.value("strategy")?.unwrap_or(Strategy::ProductFirst),
+    };
+    if base != 10 && strategy == Strategy::PalindromeFirst {
+        return Err(format!("only the product-first walk finds palindromes in base {base}"));
+    }
+
+    let bar = match (args.switch("progress"), strategy) {
+        (true, Strategy::PalindromeFirst) => progress::palindrome_bar(min, max),
+        (true, Strategy::ProductFirst) => progress::product_bar(min, max),
+        (false, _) => ProgressBar::hidden(),
+    };
+    let bounds = (min <= max).then(|| min.saturating_mul(min)..=max.saturating_mul(max));
+    let candidates: Box<dyn DoubleEndedIterator<Item = u64>> = match strategy {
+        Strategy::PalindromeFirst => {
+            Box::new(bounds.into_iter().flat_map(Palindrome::in_range).map(Palindrome::into_inner))
+        }
+        Strategy::ProductFirst => Box::new(SortedProducts::new(min, max)),
+    };
+    let candidates = match args.switch("from-largest") {
+        true => Box::new(candidates.rev()),
+        false => candidates,
+    };
+    let mut found = 0;
+    for value in candidates {
+        // the walks are lazy, so stopping here leaves the rest of the range unvisited
+        if limit.is_some_and(|limit| found >= limit) {
+            break;
+        }
+        bar.inc(1);
+        if !output::is_palindrome_in(value, base) {
+            continue;
+        }
+        let pairs: Vec<_> = factor_pairs(value, min, max).collect();
+        if !pairs.is_empty() {
+            found += 1;
+            bar.suspend(|| match format {
+                Format::Text => writeln!(out, "{} = {}", output::show(value, base), format_pairs(&pairs)),
+                Format::Json => writeln!(out, "{}", output::product_json(value, &pairs)),
+            })
+            .map_err(|e| e.to_string())?;
+        }
+    }
+    bar.finish_and_clear();
+
+    match (found, format) {
+        (0, Format::Text) => {
+            writeln!(out, "no palindromic products of factors within {min}..={max}").map_err(|e| e.to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check(args: impl Iterator<Item = String>, defaults: &Defaults, out: &mut impl Write) -> Result<(), String> {
+    let args = Args::parse(args, &["min", "max", "base", "format"], &[])?.with_defaults(defaults)?;
+    if args.positional_count() != 1 {
+        return Err("check takes exactly one VALUE".to_string());
+    }
+    let value: u64 = args
+        .positional(0)
+        .and_then(|v| v.parse().ok())
+        .ok_or("VALUE must be a non-negative integer")?;
+    let min = args.value("min")?.unwrap_or(1);
+    let max = args.value("max")?.unwrap_or(value);
+    let (base, format) = (output::base(&args)?, output::format(&args)?);
+
+    match format {
+        Format::Text => write_check(out, value, min, max, base),
+        Format::Json => {
+            let palindrome = output::is_palindrome_in(value, base);
+            let factors = palindrome.then(|| factor_pairs(value, min, max).collect::<Vec<_>>());
+            let value = json!({ "value": value, "base": base, "palindrome": palindrome, "min": min, "max": max,
+                "factors": factors });
+            writeln!(out, "{value}")
+        }
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// Report whether `value` is a palindrome in `base` and list its factor pairs within `min..=max`.
+fn write_check(out: &mut impl Write, value: u64, min: u64, max: u64, base: u32) -> io::Result<()> {
+    if let Err(e) = Palindrome::value_from_str_radix(&output::to_base(value, base), base) {
+        return writeln!(out, "{e}");
+    }
+
+    let pairs: Vec<_> = factor_pairs(value, min, max).collect();
+    writeln!(out, "{} is a palindrome", output::show(value, base))?;
+    match pairs.is_empty() {
+        true => writeln!(out, "no factor pairs within {min}..={max}"),
+        false => writeln!(out, "factor pairs within {min}..={max}: {}", format_pairs(&pairs)),
+    }
+}
@@ -0,0 +1,50 @@
+//! The `merge` command: combine the shard results of a distributed search into the answer for the whole range.
+
+use std::io::Write;
+
+use palindrome_products::{merge_shard_results, Palindrome, ShardResult};
+
+use crate::args::Args;
+use crate::format_pairs;
+
+/// Read the shard result stored as JSON in the file at `path`.
+fn read_shard(path: &str) -> Result<ShardResult, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("cannot read {path}: {e}"))?;
+    let value = serde_json::from_str(&text).map_err(|e| format!("{path}: {e}"))?;
+    ShardResult::from_json(&value).map_err(|e| format!("{path}: {e}"))
+}
+
+pub fn merge(args: impl Iterator<Item = String>, out: &mut impl Write) -> Result<(), String> {
+    let args = Args::parse(args, &[], &[])?;
+    let paths: Vec<&str> = (0..args.positional_count()).filter_map(|i| args.positional(i)).collect();
+    if paths.is_empty() {
+        return Err("merge takes at least one FILE".to_string());
+    }
+
+    let shards = paths.iter().map(|&path| Ok((path, read_shard(path)?))).collect::<Result<Vec<_>, String>>()?;
+    let merged = merge_shard_results(shards.iter().map(|&(_, result)| result)).map_err(|e| e.to_string())?;
+    let (min, max) = (merged.min(), merged.max());
+
+    // name the file and shard each extreme came from, the first in range order when several shards share it
+    let describe = |p: Palindrome, extreme: fn(&ShardResult) -> Option<Palindrome>| {
+        let source = shards
+            .iter()
+            .filter(|(_, result)| extreme(result) == Some(p))
+            .min_by_key(|(_, result)| result.shard().from())
+            .map(|(path, result)| format!(", from {path} ({}..={})", result.shard().from(), result.shard().to()))
+            .unwrap_or_default();
+        format!("{p} = {}{source}", format_pairs(&p.factors(min, max)))
+    };
+
+    let noun = match shards.len() {
+        1 => "shard",
+        _ => "shards",
+    };
+    writeln!(out, "merged {} {noun} of {min}..={max}", shards.len()).map_err(|e| e.to_string())?;
+    match merged.smallest().zip(merged.largest()) {
+        Some((smallest, largest)) => writeln!(out, "smallest: {}", describe(smallest, ShardResult::smallest))
+            .and_then(|()| writeln!(out, "largest: {}", describe(largest, ShardResult::largest))),
+        None => writeln!(out, "no palindromic products of factors within {min}..={max}"),
+    }
+    .map_err(|e| e.to_string())
+}
@@ -0,0 +1,76 @@
+//! The `--base` and `--format` options of `find`, `list` and `check`: in which digits products must read the same
+//! both ways, and how the results are written.
+
+use std::str::FromStr;
+
+use palindrome_products::reverse_digits_in_base;
+use serde_json::{json, Value};
+
+use crate::args::Args;
+
+/// How a command writes its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Lines of text for people.
+    #[default]
+    Text,
+    /// A JSON object, or for `list` one per line.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format {s:?}; expected text or json")),
+        }
+    }
+}
+
+/// The value of `--format`, text unless given.
+pub fn format(args: &Args) -> Result<Format, String> {
+    Ok(args.value("format")?.unwrap_or_default())
+}
+
+/// The value of `--base`, ten unless given. Digits above nine are written as letters, so at most 36.
+pub fn base(args: &Args) -> Result<u32, String> {
+    let base = args.value("base")?.unwrap_or(10);
+    match (2..=36).contains(&base) {
+        true => Ok(base),
+        false => Err(format!("--base must be within 2..=36, not {base}")),
+    }
+}
+
+/// Whether `value` reads the same both ways in `base`.
+pub fn is_palindrome_in(value: u64, base: u32) -> bool {
+    reverse_digits_in_base(value, base) == Some(value)
+}
+
+/// `value` written in `base`, with the letters `a` to `z` for digits above nine.
+pub fn to_base(mut value: u64, base: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit((value % base as u64) as u32, base).expect("the digit is below the base"));
+        value /= base as u64;
+        if value == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+/// `value` for text output: in decimal, followed by its digits in `base` unless that is ten, as in `9 (1001 in base
+/// 2)`.
+pub fn show(value: u64, base: u32) -> String {
+    match base {
+        10 => value.to_string(),
+        base => format!("{value} ({} in base {base})", to_base(value, base)),
+    }
+}
+
+/// A product with its factor pairs as JSON, as in `{"value": 121, "factors": [[11, 11]]}`.
+pub fn product_json(value: u64, pairs: &[(u64, u64)]) -> Value {
+    json!({ "value": value, "factors": pairs })
+}
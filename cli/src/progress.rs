@@ -0,0 +1,42 @@
+//! Progress bars for the walks of `find --progress` and `list --progress`, over the palindromes or the products.
+
+use std::ops::ControlFlow;
+
+use indicatif::{ProgressBar, ProgressStyle};
+use palindrome_products::parallel::{count_distinct_products, count_palindromes_in};
+use palindrome_products::SearchObserver;
+
+/// A bar over the palindromes between `min * min` and `max * max`, each of which a palindrome-first walk visits at
+/// most once. Counting them needs no search, so the bar knows its length and its ETA from the start. It draws on
+/// standard error, and only when that is a terminal.
+pub fn palindrome_bar(min: u64, max: u64) -> ProgressBar {
+    let total = match min <= max {
+        true => count_palindromes_in(min.saturating_mul(min)..=max.saturating_mul(max)),
+        false => 0,
+    };
+    let style = ProgressStyle::with_template("{bar:40} {pos}/{len} palindromes, {elapsed} elapsed, eta {eta}")
+        .expect("the template is valid");
+    ProgressBar::new(total).with_style(style)
+}
+
+/// A bar over the distinct products of two factors within `min..=max`, each of which a product-first walk visits at
+/// most once. Counting them takes time of its own, so a background thread does it while the walk goes on, and the
+/// bar shows its length and ETA once the count is in. It draws like [`palindrome_bar`].
+pub fn product_bar(min: u64, max: u64) -> ProgressBar {
+    let style = ProgressStyle::with_template("{bar:40} {pos}/{len} products, {elapsed} elapsed, eta {eta}")
+        .expect("the template is valid");
+    let bar = ProgressBar::no_length().with_style(style);
+    let counted = bar.clone();
+    std::thread::spawn(move || counted.set_length(count_distinct_products(min, max)));
+    bar
+}
+
+/// Advances a bar for every candidate of a search.
+pub struct BarObserver(pub ProgressBar);
+
+impl SearchObserver for BarObserver {
+    fn on_candidate(&mut self, _product: u64) -> ControlFlow<()> {
+        self.0.inc(1);
+        ControlFlow::Continue(())
+    }
+}
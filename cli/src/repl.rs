@@ -0,0 +1,134 @@
+//! The `repl` command: explore one range interactively, keeping its results between commands.
+
+use std::io::{BufRead, Write};
+
+use palindrome_products::{palindrome_products, Palindrome};
+
+use crate::{format_pairs, write_check};
+
+const HELP: &str = "\
+commands:
+    range MIN MAX   search the factors within MIN..=MAX from now on
+    smallest        print the smallest palindromic product of the range
+    largest         print the largest palindromic product of the range
+    check VALUE     report whether VALUE is a palindrome and list its factor pairs in the range
+    list N          print the N smallest palindromic products of the range
+    help            print this message
+    quit            leave";
+
+/// A factor range with everything computed about it so far.
+struct Session {
+    min: u64,
+    max: u64,
+    extremes: Option<Option<(Palindrome, Palindrome)>>,
+    /// The ascending palindromic products listed so far, and where to continue looking for more.
+    listed: Vec<Palindrome>,
+    resume: Option<u64>,
+}
+
+impl Session {
+    fn new(min: u64, max: u64) -> Session {
+        let resume = (min <= max).then(|| min.saturating_mul(min));
+        Session { min, max, extremes: None, listed: Vec::new(), resume }
+    }
+
+    fn extremes(&mut self) -> Option<(Palindrome, Palindrome)> {
+        let (min, max) = (self.min, self.max);
+        *self.extremes.get_or_insert_with(|| palindrome_products(min, max))
+    }
+
+    /// The `count` smallest palindromic products, extending the cached list as needed.
+    fn list(&mut self, count: usize) -> &[Palindrome] {
+        let upper = self.max.saturating_mul(self.max);
+        while self.listed.len() < count {
+            let Some(from) = self.resume else {
+                break;
+            };
+            let next = Palindrome::in_range(from..=upper).find(|p| !p.factors(self.min, self.max).is_empty());
+            self.resume = next.and_then(|p| p.into_inner().checked_add(1)).filter(|&from| from <= upper);
+            self.listed.extend(next);
+        }
+        &self.listed[..count.min(self.listed.len())]
+    }
+}
+
+/// Parse the single argument of `command`.
+fn argument<T: std::str::FromStr>(command: &str, words: &[&str]) -> Result<T, String> {
+    match words {
+        [value] => value.parse().map_err(|_| format!("invalid value {value:?} for {command}")),
+        _ => Err(format!("{command} takes exactly one argument")),
+    }
+}
+
+/// Run one command against the session, writing its output.
+fn execute(line: &str, session: &mut Option<Session>, out: &mut impl Write) -> Result<(), String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((&command, rest)) = words.split_first() else {
+        return Ok(());
+    };
+    let io = |e: std::io::Error| e.to_string();
+
+    if command == "range" {
+        let [min, max] = rest else {
+            return Err("range takes MIN and MAX".to_string());
+        };
+        let parse = |v: &str| v.parse::<u64>().map_err(|_| format!("invalid factor {v:?}"));
+        let (min, max) = (parse(min)?, parse(max)?);
+        *session = Some(Session::new(min, max));
+        return writeln!(out, "range {min}..={max}").map_err(io);
+    }
+    if command == "help" {
+        return writeln!(out, "{HELP}").map_err(io);
+    }
+
+    let session = session.as_mut().ok_or("no range yet; start with `range MIN MAX`")?;
+    let (min, max) = (session.min, session.max);
+    match command {
+        "smallest" | "largest" => {
+            if !rest.is_empty() {
+                return Err(format!("{command} takes no arguments"));
+            }
+            match session.extremes() {
+                Some((smallest, largest)) => {
+                    let p = if command == "smallest" { smallest } else { largest };
+                    writeln!(out, "{command}: {p} = {}", format_pairs(&p.factors(min, max)))
+                }
+                None => writeln!(out, "no palindromic products of factors within {min}..={max}"),
+            }
+            .map_err(io)
+        }
+        "check" => write_check(out, argument(command, rest)?, min, max, 10).map_err(io),
+        "list" => {
+            let count: usize = argument(command, rest)?;
+            let listed = session.list(count);
+            if listed.is_empty() && count > 0 {
+                return writeln!(out, "no palindromic products of factors within {min}..={max}").map_err(io);
+            }
+            for p in listed {
+                writeln!(out, "{p} = {}", format_pairs(&p.factors(min, max))).map_err(io)?;
+            }
+            Ok(())
+        }
+        other => Err(format!("unknown command {other:?}; type help for the commands")),
+    }
+}
+
+pub fn repl(input: impl BufRead, interactive: bool, out: &mut impl Write) -> Result<(), String> {
+    let mut session = None;
+    let mut lines = input.lines();
+    loop {
+        if interactive {
+            write!(out, "> ").and_then(|()| out.flush()).map_err(|e| e.to_string())?;
+        }
+        let Some(line) = lines.next() else {
+            return Ok(());
+        };
+        let line = line.map_err(|e| e.to_string())?;
+        if matches!(line.trim(), "quit" | "exit") {
+            return Ok(());
+        }
+        if let Err(message) = execute(&line, &mut session, out) {
+            writeln!(out, "error: {message}").map_err(|e| e.to_string())?;
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! The `sweep` command: search the n-digit factor range for each n in a range of digit counts.
+
+use std::io::Write;
+use std::ops::RangeInclusive;
+use std::time::Instant;
+
+use palindrome_products::{digit_range, palindrome_products, Palindrome};
+
+use crate::args::Args;
+use crate::table::write_table;
+use crate::format_pairs;
+
+/// Parse `a..=b`, `a..b` or a single digit count.
+fn parse_digits(s: &str) -> Result<RangeInclusive<u32>, String> {
+    let invalid = || format!("invalid digit range {s:?}");
+    let number = |n: &str| n.parse::<u32>().map_err(|_| invalid());
+    let digits = if let Some((start, end)) = s.split_once("..=") {
+        number(start)?..=number(end)?
+    } else if let Some((start, end)) = s.split_once("..") {
+        number(start)?..=number(end)?.checked_sub(1).ok_or_else(invalid)?
+    } else {
+        number(s)?..=number(s)?
+    };
+
+    match digits.is_empty() || digit_range(*digits.start()).is_none() || digit_range(*digits.end()).is_none() {
+        true => Err(format!("digit range {s:?} must be a non-empty range within 1..=20")),
+        false => Ok(digits),
+    }
+}
+
+pub fn sweep(args: impl Iterator<Item = String>, out: &mut impl Write) -> Result<(), String> {
+    let args = Args::parse(args, &["digits"], &[])?;
+    let digits = parse_digits(&args.required::<String>("digits")?)?;
+
+    let show = |p: Option<Palindrome>| p.map_or("-".to_string(), |p| p.to_string());
+    let mut rows = Vec::new();
+    for n in digits {
+        let factors = digit_range(n).expect("digit counts were validated");
+        let (min, max) = (*factors.start(), *factors.end());
+
+        let start = Instant::now();
+        let products = palindrome_products(min, max);
+        let elapsed = start.elapsed();
+
+        let (smallest, largest) = products.unzip();
+        let pairs = largest.map_or("-".to_string(), |p| format_pairs(&p.factors(min, max)));
+        rows.push([n.to_string(), show(smallest), show(largest), pairs, format!("{elapsed:.3?}")]);
+    }
+
+    write_table(out, ["digits", "smallest", "largest", "factors", "time"], &rows)
+}
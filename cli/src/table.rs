@@ -0,0 +1,21 @@
+//! Column-aligned plain text tables.
+
+use std::io::Write;
+
+/// Write `header` and `rows` with every column padded to its widest cell.
+pub fn write_table<const N: usize>(
+    out: &mut impl Write,
+    header: [&str; N],
+    rows: &[[String; N]],
+) -> Result<(), String> {
+    let header = header.map(String::from);
+    let widths: Vec<usize> = (0..N)
+        .map(|column| rows.iter().chain([&header]).map(|row| row[column].len()).max().unwrap_or(0))
+        .collect();
+
+    for row in [&header].into_iter().chain(rows) {
+        let line: Vec<String> = row.iter().zip(&widths).map(|(cell, &width)| format!("{cell:<width$}")).collect();
+        writeln!(out, "{}", line.join("  ").trim_end()).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
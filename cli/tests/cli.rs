@@ -0,0 +1,511 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_palindrome-products"))
+        .args(args)
+        .output()
+        .expect("run palindrome-products")
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = run(args);
+    assert!(output.status.success(), "{args:?} failed: {}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).expect("utf-8 output")
+}
+
+#[test]
+/// `find` prints the extremes with their factors
+fn test_find() {
+    assert_eq!(stdout(&["find", "--min", "10", "--max", "99"]), "smallest: 121 = 11 x 11\nlargest: 9009 = 91 x 99\n");
+    assert_eq!(stdout(&["find", "--min=15", "--max=15"]), "no palindromic products of factors within 15..=15\n");
+}
+
+#[test]
+/// `find --progress` walks the palindromes but gives the same answer, drawing nothing when not on a terminal
+fn test_find_progress() {
+    for (min, max) in [("10", "99"), ("100", "999"), ("15", "15"), ("5", "4")] {
+        let args = ["find", "--min", min, "--max", max];
+        let output = run(&[&args[..], &["--progress"]].concat());
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), stdout(&args), "{min}..={max}");
+        assert!(output.stderr.is_empty());
+    }
+}
+
+#[test]
+/// `find --progress` keeps the chosen strategy and base, and warns that it cannot search in parallel
+fn test_find_progress_options() {
+    for extra in [&["--strategy", "product-first"][..], &["--strategy", "palindrome-first"], &["--base", "2"]] {
+        let args = [&["find", "--min", "10", "--max", "99"][..], extra].concat();
+        let output = run(&[&args[..], &["--progress"]].concat());
+        assert!(output.status.success(), "{extra:?}");
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), stdout(&args), "{extra:?}");
+        assert!(output.stderr.is_empty(), "{extra:?}");
+    }
+
+    for extra in [&["--parallel"][..], &["--threads", "2"]] {
+        let output = run(&[&["find", "--min", "10", "--max", "99", "--progress"][..], extra].concat());
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), stdout(&["find", "--min", "10", "--max", "99"]));
+        assert!(String::from_utf8_lossy(&output.stderr).starts_with("warning: --progress searches on one thread"));
+    }
+    let output = run(&["find", "--min", "10", "--max", "99", "--progress", "--threads", "1"]);
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+/// `list` prints every palindromic product with its factor pairs, ascending
+fn test_list() {
+    let output = stdout(&["list", "--min", "1", "--max", "9"]);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[..4], ["1 = 1 x 1", "2 = 1 x 2", "3 = 1 x 3", "4 = 1 x 4, 2 x 2"]);
+    // no two digit palindrome is a product of single digits
+    assert_eq!(lines.last(), Some(&"9 = 1 x 9, 3 x 3"));
+    assert_eq!(lines.len(), 9, "{output}");
+
+    let output = stdout(&["list", "--min", "10", "--max", "99", "--progress"]);
+    assert_eq!(output.lines().next(), Some("121 = 11 x 11"));
+    assert_eq!(output.lines().last(), Some("9009 = 91 x 99"));
+    assert_eq!(stdout(&["list", "--min", "10", "--max", "99"]), output);
+    assert_eq!(stdout(&["list", "--min", "10", "--max", "99", "--progress", "--strategy", "product-first"]), output);
+    for (min, max) in [("15", "15"), ("5", "4")] {
+        let expected = format!("no palindromic products of factors within {min}..={max}\n");
+        assert_eq!(stdout(&["list", "--min", min, "--max", max]), expected);
+    }
+}
+
+#[test]
+/// `list --limit` stops after that many products, from the smallest or with `--from-largest` from the largest
+fn test_list_limit() {
+    let all = stdout(&["list", "--min", "10", "--max", "99"]);
+    let all: Vec<&str> = all.lines().collect();
+    for strategy in ["product-first", "palindrome-first"] {
+        let args = ["list", "--min", "10", "--max", "99", "--strategy", strategy];
+        let list = |extra: &[&str]| stdout(&[&args[..], extra].concat());
+        assert_eq!(list(&["--limit", "2"]).lines().collect::<Vec<_>>(), all[..2], "{strategy}");
+        let largest: Vec<&str> = all.iter().rev().copied().collect();
+        assert_eq!(list(&["--limit", "3", "--from-largest"]).lines().collect::<Vec<_>>(), largest[..3], "{strategy}");
+        assert_eq!(list(&["--from-largest"]).lines().collect::<Vec<_>>(), largest, "{strategy}");
+        assert_eq!(list(&["--limit", "1000"]).lines().collect::<Vec<_>>(), all, "{strategy}");
+    }
+    let json = stdout(&["list", "--min", "1", "--max", "9", "--limit=1", "--format", "json"]);
+    assert_eq!(json, "{\"factors\":[[1,1]],\"value\":1}\n");
+    assert_eq!(run(&["list", "--min", "1", "--max", "9", "--limit", "0"]).status.code(), Some(2));
+    // the walk stops at the largest product, far before the smallest one of the eight digit factors
+    let largest = stdout(&["list", "--min", "10000000", "--max", "99999999", "--limit", "1", "--from-largest"]);
+    assert_eq!(largest, "9999000000009999 = 99990001 x 99999999\n");
+}
+
+#[test]
+/// `check` enumerates the factor pairs of a palindrome within the range
+fn test_check_palindrome() {
+    assert_eq!(
+        stdout(&["check", "906609", "--min", "100", "--max", "999"]),
+        "906609 is a palindrome\nfactor pairs within 100..=999: 913 x 993\n"
+    );
+    assert_eq!(stdout(&["check", "121"]), "121 is a palindrome\nfactor pairs within 1..=121: 1 x 121, 11 x 11\n");
+    assert_eq!(
+        stdout(&["check", "9009", "--min", "100", "--max", "999"]),
+        "9009 is a palindrome\nno factor pairs within 100..=999\n"
+    );
+}
+
+#[test]
+/// `check` explains why a value is not a palindrome
+fn test_check_not_palindrome() {
+    assert_eq!(stdout(&["check", "906608"]), "906608 is not a palindrome: it reads 806609 backwards\n");
+}
+
+#[test]
+/// `--base` looks for palindromes in other bases, which only the sequential product-first search supports
+fn test_base() {
+    let find = stdout(&["find", "--min", "10", "--max", "99", "--base", "2"]);
+    assert_eq!(
+        find,
+        "smallest: 165 (10100101 in base 2) = 11 x 15\nlargest: 9009 (10001100110001 in base 2) = 91 x 99\n"
+    );
+    assert_eq!(
+        stdout(&["check", "9", "--base", "2"]),
+        "9 (1001 in base 2) is a palindrome\nfactor pairs within 1..=9: 1 x 9, 3 x 3\n"
+    );
+    assert_eq!(stdout(&["check", "10", "--base", "2"]), "10 is not a palindrome in base 2: it reads 5 backwards\n");
+    let list = stdout(&["list", "--min", "1", "--max", "4", "--base", "2"]);
+    assert_eq!(list, "1 (1 in base 2) = 1 x 1\n3 (11 in base 2) = 1 x 3\n9 (1001 in base 2) = 3 x 3\n");
+    let decimal = ["find", "--min", "10", "--max", "99"];
+    assert_eq!(stdout(&[&decimal[..], &["--base", "10"]].concat()), stdout(&decimal));
+
+    for args in [
+        &["find", "--min", "1", "--max", "9", "--base", "2", "--strategy", "palindrome-first"][..],
+        &["find", "--min", "1", "--max", "9", "--base", "2", "--parallel"],
+        &["find", "--min", "1", "--max", "9", "--base", "2", "--threads", "2"],
+        &["list", "--min", "1", "--max", "9", "--base", "2", "--strategy", "palindrome-first"],
+        &["check", "9", "--base", "1"],
+        &["check", "9", "--base", "37"],
+    ] {
+        assert_eq!(run(args).status.code(), Some(2), "{args:?}");
+    }
+}
+
+#[test]
+/// `--format json` writes one object, or for `list` one per product and line
+fn test_format_json() {
+    assert_eq!(
+        stdout(&["find", "--min", "10", "--max", "99", "--format", "json"]),
+        concat!(
+            r#"{"base":10,"largest":{"factors":[[91,99]],"value":9009},"max":99,"min":10,"#,
+            r#""smallest":{"factors":[[11,11]],"value":121}}"#,
+            "\n"
+        )
+    );
+    assert_eq!(
+        stdout(&["find", "--min", "15", "--max", "15", "--format=json"]),
+        "{\"base\":10,\"largest\":null,\"max\":15,\"min\":15,\"smallest\":null}\n"
+    );
+    assert_eq!(
+        stdout(&["check", "121", "--format", "json"]),
+        "{\"base\":10,\"factors\":[[1,121],[11,11]],\"max\":121,\"min\":1,\"palindrome\":true,\"value\":121}\n"
+    );
+    let list = stdout(&["list", "--min", "1", "--max", "3", "--format", "json"]);
+    assert_eq!(list.lines().collect::<Vec<_>>(), [
+        "{\"factors\":[[1,1]],\"value\":1}",
+        "{\"factors\":[[1,2]],\"value\":2}",
+        "{\"factors\":[[1,3]],\"value\":3}",
+        "{\"factors\":[[2,2]],\"value\":4}",
+        "{\"factors\":[[2,3]],\"value\":6}",
+        "{\"factors\":[[3,3]],\"value\":9}",
+    ]);
+    assert_eq!(stdout(&["list", "--min", "15", "--max", "15", "--format", "json"]), "");
+    assert_eq!(run(&["find", "--min", "1", "--max", "9", "--format", "xml"]).status.code(), Some(2));
+}
+
+#[test]
+/// `--strategy` picks the search of `find` and the walk of `list` without changing their output
+fn test_strategy_option() {
+    for (min, max) in [("10", "99"), ("100", "999"), ("15", "15")] {
+        for command in ["find", "list"] {
+            let expected = stdout(&[command, "--min", min, "--max", max]);
+            for strategy in ["product-first", "palindrome-first"] {
+                let output = stdout(&[command, "--min", min, "--max", max, "--strategy", strategy]);
+                assert_eq!(output, expected, "{command} {min}..={max} {strategy}");
+            }
+        }
+    }
+    assert_eq!(run(&["find", "--min", "1", "--max", "9", "--strategy", "sideways"]).status.code(), Some(2));
+}
+
+#[test]
+/// invalid invocations fail with a usage message
+fn test_invalid_arguments() {
+    let invalid: [&[&str]; 6] = [
+        &[],
+        &["frobnicate"],
+        &["find", "--min", "10"],
+        &["check"],
+        &["check", "x"],
+        &["find", "--bogus", "1"],
+    ];
+    for args in invalid {
+        let output = run(args);
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("usage:"), "{args:?}");
+    }
+}
+
+#[test]
+/// parallel and sequential searches of either strategy print identical results, and so do the threads which a
+/// thread count above one asks for without --parallel
+fn test_find_parallel_matches_sequential() {
+    let sequential = stdout(&["find", "--min", "100", "--max", "999", "--threads", "1"]);
+    assert_eq!(sequential, "smallest: 10201 = 101 x 101\nlargest: 906609 = 913 x 993\n");
+    for strategy in ["product-first", "palindrome-first"] {
+        let args = ["find", "--min", "100", "--max", "999", "--strategy", strategy];
+        assert_eq!(stdout(&[&args[..], &["--parallel"]].concat()), sequential, "{strategy}");
+        assert_eq!(stdout(&[&args[..], &["--parallel", "--threads", "3"]].concat()), sequential, "{strategy}");
+        assert_eq!(stdout(&[&args[..], &["--threads", "3"]].concat()), sequential, "{strategy}");
+    }
+    // wide enough to be searched in parallel by default where there are several CPUs
+    assert_eq!(
+        stdout(&["find", "--min", "100000", "--max", "999999"]),
+        "smallest: 10000200001 = 100001 x 100001\nlargest: 999000000999 = 999001 x 999999\n"
+    );
+    assert_eq!(
+        stdout(&["find", "--min", "1000", "--max", "9999"]),
+        "smallest: 1002001 = 1001 x 1001\nlargest: 99000099 = 9901 x 9999\n"
+    );
+}
+
+#[test]
+/// thread counts must be positive
+fn test_find_rejects_zero_threads() {
+    assert_eq!(run(&["find", "--min", "1", "--max", "9", "--threads", "0"]).status.code(), Some(2));
+}
+
+#[test]
+/// `bench` prints one row per algorithm with identical answers
+fn test_bench() {
+    let output = stdout(&["bench", "--min", "10", "--max", "99", "--runs", "1"]);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "factors 10..=99, fastest of 1 run(s)");
+    assert!(lines[1].starts_with("algorithm"));
+    assert_eq!(lines.len(), 5);
+    for (line, name) in lines[2..].iter().zip(["product-first ", "product-first (parallel)", "palindrome-first"]) {
+        assert!(line.starts_with(name), "{line}");
+        assert!(line.contains(" 121 ") && line.contains(" 9009 "), "{line}");
+    }
+}
+
+#[test]
+/// `bench --strategy` restricts the table to one strategy
+fn test_bench_single_strategy() {
+    let output = stdout(&["bench", "--min", "10", "--max", "99", "--strategy", "palindrome-first"]);
+    assert_eq!(output.lines().count(), 3);
+    assert!(output.lines().last().is_some_and(|line| line.starts_with("palindrome-first")));
+    assert_eq!(run(&["bench", "--min", "1", "--max", "9", "--strategy", "fastest"]).status.code(), Some(2));
+}
+
+#[test]
+/// `sweep` prints one row per digit count
+fn test_sweep() {
+    let output = stdout(&["sweep", "--digits", "1..=2"]);
+    let lines: Vec<Vec<&str>> = output.lines().map(|line| line.split_whitespace().collect()).collect();
+    assert_eq!(lines.len(), 3, "{output}");
+    assert_eq!(lines[0], ["digits", "smallest", "largest", "factors", "time"]);
+    assert_eq!(lines[1][..6], ["1", "1", "9", "1", "x", "9,"]);
+    assert_eq!(lines[2][..6], ["2", "121", "9009", "91", "x", "99"]);
+
+    for digits in ["2", "2..3"] {
+        let output = stdout(&["sweep", "--digits", digits]);
+        let rows: Vec<Vec<&str>> = output.lines().skip(1).map(|line| line.split_whitespace().collect()).collect();
+        assert_eq!(rows.len(), 1, "{output}");
+        assert_eq!(rows[0][..6], ["2", "121", "9009", "91", "x", "99"]);
+    }
+}
+
+#[test]
+/// `sweep` rejects malformed and out-of-range digit counts
+fn test_sweep_invalid_digits() {
+    for digits in ["", "x", "3..=2", "0..=3", "2..=21", "3..3"] {
+        let output = run(&["sweep", "--digits", digits]);
+        assert_eq!(output.status.code(), Some(2), "{digits:?}");
+    }
+}
+
+/// Run `repl` with `input` on standard input.
+fn repl(input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_palindrome-products"))
+        .arg("repl")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("run palindrome-products");
+    child.stdin.take().expect("piped stdin").write_all(input.as_bytes()).expect("write input");
+    let output = child.wait_with_output().expect("wait for palindrome-products");
+    assert!(output.status.success(), "repl failed: {}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).expect("utf-8 output")
+}
+
+#[test]
+/// `repl` keeps its range between commands
+fn test_repl() {
+    let input = "range 10 99\nsmallest\nlargest\nlist 2\nlist 4\ncheck 9009\nquit\nsmallest\n";
+    assert_eq!(
+        repl(input),
+        "range 10..=99\n\
+         smallest: 121 = 11 x 11\n\
+         largest: 9009 = 91 x 99\n\
+         121 = 11 x 11\n\
+         242 = 11 x 22\n\
+         121 = 11 x 11\n\
+         242 = 11 x 22\n\
+         252 = 12 x 21, 14 x 18\n\
+         272 = 16 x 17\n\
+         9009 is a palindrome\n\
+         factor pairs within 10..=99: 91 x 99\n"
+    );
+}
+
+#[test]
+/// `repl` reports errors and carries on
+fn test_repl_errors() {
+    let input = "smallest\nrange 10\nrange 15 15\nlargest\nlist 3\nfrobnicate\nlist x\n";
+    assert_eq!(
+        repl(input),
+        "error: no range yet; start with `range MIN MAX`\n\
+         error: range takes MIN and MAX\n\
+         range 15..=15\n\
+         no palindromic products of factors within 15..=15\n\
+         no palindromic products of factors within 15..=15\n\
+         error: unknown command \"frobnicate\"; type help for the commands\n\
+         error: invalid value \"x\" for list\n"
+    );
+}
+
+/// A fresh empty directory for one test, holding `palindrome-products.toml` with `config` if given.
+fn config_dir(name: &str, config: Option<&str>) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("palindrome-products-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("create a temporary directory");
+    if let Some(config) = config {
+        std::fs::write(dir.join("palindrome-products.toml"), config).expect("write the config");
+    }
+    dir
+}
+
+fn run_in(dir: &std::path::Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_palindrome-products"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("run palindrome-products")
+}
+
+#[test]
+/// a config file in the working directory supplies defaults which flags override
+fn test_config_discovered() {
+    let dir = config_dir("discovered", Some("min = 10\nmax = 99\n[find]\nthreads = 1\n"));
+    let output = run_in(&dir, &["find"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "smallest: 121 = 11 x 11\nlargest: 9009 = 91 x 99\n");
+
+    let output = run_in(&dir, &["find", "--min", "1", "--max=9"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "smallest: 1 = 1 x 1\nlargest: 9 = 1 x 9, 3 x 3\n");
+    let output = String::from_utf8(run_in(&dir, &["check", "121"]).stdout).unwrap();
+    assert_eq!(output, "121 is a palindrome\nfactor pairs within 10..=99: 11 x 11\n");
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+/// `--config` names the file to read, anywhere on the command line, in place of the discovered one
+fn test_config_explicit() {
+    let dir = config_dir("explicit", Some("min = 1\nmax = 9\n"));
+    std::fs::write(dir.join("other.toml"), "min = 100\nmax = 999\nstrategy = \"palindrome-first\"\n").unwrap();
+    let output = run_in(&dir, &["--config", "other.toml", "find"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "smallest: 10201 = 101 x 101\nlargest: 906609 = 913 x 993\n");
+
+    let output = run_in(&dir, &["bench", "--runs", "1", "--config=other.toml"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 3, "{stdout}");
+    assert!(stdout.lines().last().is_some_and(|line| line.starts_with("palindrome-first")));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+/// unreadable and invalid config files are errors
+fn test_config_errors() {
+    let dir = config_dir("errors", None);
+    for (config, message) in [
+        ("digits = 3\n", "unknown key \"digits\""),
+        ("min = 1\nmax = 9\n[find]\nruns = 3\n", "unknown key \"runs\""),
+        ("min = 1\nmax = 9\nbase = 37\n", "--base must be within 2..=36"),
+        ("min = 1\nmax = 9\nformat = \"xml\"\n", "invalid value \"xml\" for --format"),
+        ("min = true\n", "min must be an integer or a string"),
+        ("min = \n", "invalid config"),
+        ("min = \"ten\"\nmax = 99\n", "invalid value \"ten\" for --min"),
+    ] {
+        std::fs::write(dir.join("palindrome-products.toml"), config).unwrap();
+        let output = run_in(&dir, &["find"]);
+        assert_eq!(output.status.code(), Some(2), "{config}");
+        assert!(String::from_utf8_lossy(&output.stderr).contains(message), "{config}");
+    }
+
+    let output = run_in(&dir, &["find", "--config", "missing.toml"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot read missing.toml"));
+    assert_eq!(run_in(&dir, &["find", "--config"]).status.code(), Some(2));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+/// the config sets base, format and strategy like the flags do, and tables scope keys to one command, while a key
+/// which a command does not take is an error rather than ignored
+fn test_config_options() {
+    let config = "min = 10\nmax = 99\nformat = \"json\"\n[find]\nbase = 2\nstrategy = \"product-first\"\n";
+    let dir = config_dir("options", Some(config));
+    let output = String::from_utf8(run_in(&dir, &["find"]).stdout).unwrap();
+    assert!(output.starts_with("{\"base\":2,"), "{output}");
+    let output = String::from_utf8(run_in(&dir, &["list", "--format", "text"]).stdout).unwrap();
+    assert_eq!(output.lines().next(), Some("121 = 11 x 11"));
+    let output = String::from_utf8(run_in(&dir, &["check", "121"]).stdout).unwrap();
+    assert!(output.contains("\"palindrome\":true"), "{output}");
+
+    std::fs::write(dir.join("palindrome-products.toml"), "min = 10\nmax = 99\nthreads = 2\n").unwrap();
+    assert!(run_in(&dir, &["find"]).status.success());
+    for command in ["check", "list", "bench"] {
+        let output = run_in(&dir, &[command, "121"]);
+        assert_eq!(output.status.code(), Some(2), "{command}");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("the config sets threads"), "{command}");
+    }
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+/// the example config of the usage text works, and its thread count selects the parallel search as --threads does
+fn test_config_threads() {
+    let config = "min = 100\nmax = 999\n[find]\nthreads = 4\nstrategy = \"palindrome-first\"\n";
+    let dir = config_dir("threads", Some(config));
+    let output = run_in(&dir, &["find"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "smallest: 10201 = 101 x 101\nlargest: 906609 = 913 x 993\n");
+    assert!(run_in(&dir, &["find", "--parallel"]).status.success());
+
+    // the parallel search cannot use other bases or show progress, so those tell which search the config chose
+    let output = run_in(&dir, &["find", "--strategy", "product-first", "--base", "2"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("only the sequential product-first search"));
+    let output = run_in(&dir, &["find", "--progress"]);
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("warning: --progress searches on one thread"));
+
+    std::fs::write(dir.join("palindrome-products.toml"), config.replace("threads = 4", "threads = 1")).unwrap();
+    assert!(run_in(&dir, &["find", "--strategy", "product-first", "--base", "2"]).status.success());
+    assert!(run_in(&dir, &["find", "--progress"]).stderr.is_empty());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+/// Write the results of splitting `min..=max` into `shards` shards to `shard-<i>.json` files in a fresh directory.
+fn shard_files(name: &str, min: u64, max: u64, shards: usize) -> (std::path::PathBuf, Vec<String>) {
+    let dir = config_dir(name, None);
+    let paths = palindrome_products::shard_range(min, max, shards)
+        .iter()
+        .enumerate()
+        .map(|(i, shard)| {
+            let path = dir.join(format!("shard-{i}.json"));
+            std::fs::write(&path, shard.search().to_json().to_string()).expect("write a shard");
+            path.to_string_lossy().into_owned()
+        })
+        .collect();
+    (dir, paths)
+}
+
+#[test]
+/// `merge` combines shard files given in any order, naming the shard behind each extreme
+fn test_merge() {
+    let (_, mut paths) = shard_files("merge", 10, 99, 4);
+    paths.reverse();
+    let args: Vec<&str> = ["merge"].into_iter().chain(paths.iter().map(String::as_str)).collect();
+    let output = stdout(&args);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "merged 4 shards of 10..=99");
+    assert!(lines[1].starts_with("smallest: 121 = 11 x 11, from ") && lines[1].ends_with("shard-0.json (10..=54)"));
+    assert!(lines[2].starts_with("largest: 9009 = 91 x 99, from ") && lines[2].ends_with("shard-3.json (87..=99)"));
+
+    let (_, paths) = shard_files("merge-empty", 15, 15, 1);
+    let expected = "merged 1 shard of 15..=15\nno palindromic products of factors within 15..=15\n";
+    assert_eq!(stdout(&["merge", &paths[0]]), expected);
+}
+
+#[test]
+/// `merge` rejects missing shards, unreadable files and malformed results
+fn test_merge_errors() {
+    let (dir, paths) = shard_files("merge-errors", 10, 99, 4);
+    let stderr = |args: &[&str]| {
+        let output = run(args);
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        String::from_utf8(output.stderr).unwrap()
+    };
+
+    assert!(stderr(&["merge", &paths[0], &paths[2], &paths[3]]).starts_with("error: expected a shard starting at"));
+    assert!(stderr(&["merge"]).starts_with("error: merge takes at least one FILE"));
+    assert!(stderr(&["merge", "missing.json"]).starts_with("error: cannot read missing.json"));
+    let bad = dir.join("bad.json");
+    std::fs::write(&bad, r#"{"schema_version": 1, "shard": {"min": 10, "from": 10}}"#).unwrap();
+    assert!(stderr(&["merge", bad.to_str().unwrap()]).contains("missing or invalid field \"to\""));
+}
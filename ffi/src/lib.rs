@@ -0,0 +1,148 @@
+//! UniFFI bindings to the palindrome product search, for Kotlin and Swift.
+//!
+//! Build the library, then generate the bindings from it:
+//!
+//! ```text
+//! cargo build -p palindrome-products-ffi --release
+//! cargo run -p palindrome-products-ffi --features bindgen --bin uniffi-bindgen -- \
+//!     generate --library target/release/libpalindrome_products_ffi.so --language kotlin --out-dir out
+//! ```
+//!
+//! A [`Palindrome`] crosses the boundary as its `u64` value; values coming from the foreign side are validated, so
+//! passing a non-palindrome raises an error there.
+
+use std::fmt;
+
+use palindrome_products::{Palindrome, Search, SearchError, Strategy};
+
+uniffi::setup_scaffolding!();
+
+uniffi::custom_type!(Palindrome, u64, {
+    remote,
+    lower: |p| p.into_inner(),
+    try_lift: |value| Ok(Palindrome::try_new(value)?),
+});
+
+/// Errors raised to the foreign side.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Error)]
+pub enum PalindromeError {
+    /// The value is not a base ten palindrome.
+    NotPalindrome { value: u64 },
+    /// The search needs more working memory than its limit allows.
+    MemoryLimit { required: u64, limit: u64 },
+    /// The search was stopped before it finished.
+    Stopped,
+}
+
+impl fmt::Display for PalindromeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PalindromeError::NotPalindrome { value } => write!(f, "{value} is not a palindrome"),
+            PalindromeError::MemoryLimit { required, limit } => {
+                write!(f, "search needs about {required} bytes of working memory, more than the limit of {limit}")
+            }
+            PalindromeError::Stopped => write!(f, "search stopped before it finished"),
+        }
+    }
+}
+
+impl std::error::Error for PalindromeError {}
+
+impl From<SearchError> for PalindromeError {
+    fn from(e: SearchError) -> Self {
+        match e {
+            SearchError::MemoryLimit { required, limit } => {
+                PalindromeError::MemoryLimit { required: required as u64, limit: limit as u64 }
+            }
+            SearchError::Stopped => PalindromeError::Stopped,
+        }
+    }
+}
+
+/// The search strategy, mirroring [`Strategy`] with an extra choice to let the search pick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, uniffi::Enum)]
+pub enum SearchStrategy {
+    #[default]
+    Automatic,
+    ProductFirst,
+    PalindromeFirst,
+}
+
+/// Options of a search, mirroring the [`Search`] builder.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct SearchOptions {
+    pub min: u64,
+    pub max: u64,
+    pub strategy: SearchStrategy,
+    #[uniffi(default)]
+    pub max_memory: Option<u64>,
+    #[uniffi(default)]
+    pub palindromic_factors: bool,
+    #[uniffi(default = 1)]
+    pub step: u64,
+    #[uniffi(default)]
+    pub exclude_factors: Vec<u64>,
+}
+
+/// A pair of factors `a <= b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Record)]
+pub struct FactorPair {
+    pub a: u64,
+    pub b: u64,
+}
+
+/// A palindromic product together with its factor pairs within the searched range.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct Product {
+    pub value: Palindrome,
+    pub factors: Vec<FactorPair>,
+}
+
+/// The smallest and largest palindromic products of a search.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct SearchResult {
+    pub smallest: Option<Product>,
+    pub largest: Option<Product>,
+}
+
+/// Create a palindrome, raising an error if `value` is not one.
+#[uniffi::export]
+pub fn palindrome(value: u64) -> Result<Palindrome, PalindromeError> {
+    Palindrome::try_new(value).map_err(|e| PalindromeError::NotPalindrome { value: e.value() })
+}
+
+/// Whether `value` is a base ten palindrome.
+#[uniffi::export]
+pub fn is_palindrome(value: u64) -> bool {
+    Palindrome::new(value).is_some()
+}
+
+/// Every pair of factors within `min..=max` whose product is `p`.
+#[uniffi::export]
+pub fn factors(p: Palindrome, min: u64, max: u64) -> Vec<FactorPair> {
+    p.factors(min, max).into_iter().map(|(a, b)| FactorPair { a, b }).collect()
+}
+
+/// Run a palindrome product search. A step of zero is treated as one.
+#[uniffi::export]
+pub fn search(options: SearchOptions) -> Result<SearchResult, PalindromeError> {
+    let mut search = Search::new(options.min, options.max)
+        .palindromic_factors(options.palindromic_factors)
+        .step(usize::try_from(options.step.max(1)).unwrap_or(usize::MAX))
+        .exclude_factors(&options.exclude_factors);
+    search = match options.strategy {
+        SearchStrategy::Automatic => search,
+        SearchStrategy::ProductFirst => search.strategy(Strategy::ProductFirst),
+        SearchStrategy::PalindromeFirst => search.strategy(Strategy::PalindromeFirst),
+    };
+    if let Some(bytes) = options.max_memory {
+        search = search.max_memory(usize::try_from(bytes).unwrap_or(usize::MAX));
+    }
+
+    let result = search.run()?;
+    let product = |p: Palindrome| Product {
+        value: p,
+        factors: search.factors(p).into_iter().map(|(a, b)| FactorPair { a, b }).collect(),
+    };
+    Ok(SearchResult { smallest: result.smallest().map(product), largest: result.largest().map(product) })
+}
@@ -0,0 +1,49 @@
+use palindrome_products::{Palindrome, PalindromeProducts};
+use palindrome_products_ffi::{
+    factors, is_palindrome, palindrome, search, FactorPair, PalindromeError, SearchOptions, SearchStrategy,
+};
+
+fn options(min: u64, max: u64) -> SearchOptions {
+    SearchOptions {
+        min,
+        max,
+        strategy: SearchStrategy::Automatic,
+        max_memory: None,
+        palindromic_factors: false,
+        step: 1,
+        exclude_factors: Vec::new(),
+    }
+}
+
+#[test]
+/// palindromes are validated on the way in
+fn test_palindrome() {
+    assert_eq!(palindrome(9009).map(Palindrome::into_inner), Ok(9009));
+    assert_eq!(palindrome(9010), Err(PalindromeError::NotPalindrome { value: 9010 }));
+    assert!(is_palindrome(121));
+    assert!(!is_palindrome(123));
+    assert_eq!(factors(palindrome(9009).unwrap(), 10, 99), [FactorPair { a: 91, b: 99 }]);
+}
+
+#[test]
+/// the search agrees with the core crate for every strategy
+fn test_search() {
+    for strategy in [SearchStrategy::Automatic, SearchStrategy::ProductFirst, SearchStrategy::PalindromeFirst] {
+        let result = search(SearchOptions { strategy, ..options(10, 99) }).unwrap();
+        let expected = PalindromeProducts::new(10, 99);
+        assert_eq!(result.smallest.as_ref().map(|p| p.value), expected.smallest());
+        assert_eq!(result.largest.as_ref().map(|p| p.value), expected.largest());
+        assert_eq!(result.largest.unwrap().factors, [FactorPair { a: 91, b: 99 }]);
+    }
+    assert_eq!(search(options(2, 1)).map(|r| r.smallest), Ok(None));
+}
+
+#[test]
+/// search options and errors carry across
+fn test_search_options() {
+    let result = search(SearchOptions { exclude_factors: vec![91], ..options(10, 99) }).unwrap();
+    assert_ne!(result.largest.map(|p| p.value.into_inner()), Some(9009));
+
+    let limited = SearchOptions { strategy: SearchStrategy::ProductFirst, max_memory: Some(10), ..options(10, 99) };
+    assert!(matches!(search(limited), Err(PalindromeError::MemoryLimit { limit: 10, .. })));
+}
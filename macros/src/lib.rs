@@ -0,0 +1,118 @@
+//! Compile-time palindrome tables for `palindrome-products`, re-exported from it behind the `macros` feature.
+//!
+//! ```ignore
+//! use palindrome_products::{palindromes, Palindrome};
+//!
+//! static SMALL: &[Palindrome] = palindromes!(1..=10_000);
+//! assert_eq!(SMALL.len(), 198);
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::Literal;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, LitInt, Token};
+
+/// Tables longer than this are rejected rather than slowing the build to a crawl.
+const MAX_LEN: usize = 1 << 20;
+
+/// An integer range with literal bounds, `a..b` or `a..=b`.
+struct Bounds {
+    start: u64,
+    end: Option<u64>,
+}
+
+impl Parse for Bounds {
+    fn parse(input: ParseStream) -> syn::Result<Bounds> {
+        let start = input.parse::<LitInt>()?.base10_parse()?;
+        let inclusive = input.peek(Token![..=]);
+        match inclusive {
+            true => input.parse::<Token![..=]>().map(drop)?,
+            false => input.parse::<Token![..]>().map(drop)?,
+        }
+        let end = input.parse::<LitInt>()?.base10_parse::<u64>()?;
+        let end = match inclusive {
+            true => Some(end),
+            false => end.checked_sub(1),
+        };
+        Ok(Bounds { start, end })
+    }
+}
+
+/// Number of decimal digits of `n`. Zero has one digit.
+fn digit_count(n: u128) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+
+/// Build the palindrome with `len` digits whose leading `ceil(len / 2)` digits are `half`.
+fn mirror(half: u128, len: u32) -> u128 {
+    let (mut value, mut rest) = (half, half / 10u128.pow(len % 2));
+    while rest > 0 {
+        value = value * 10 + rest % 10;
+        rest /= 10;
+    }
+    value
+}
+
+/// The smallest palindrome which is greater than or equal to `n`, as in the crate's own generator.
+fn next_palindrome(n: u128) -> u128 {
+    if n < 10 {
+        return n;
+    }
+
+    let len = digit_count(n);
+    let half = n / 10u128.pow(len / 2);
+    let candidate = mirror(half, len);
+    if candidate >= n {
+        candidate
+    } else if half + 1 == 10u128.pow(len.div_ceil(2)) {
+        // every digit of the half is a nine: the next palindrome has one more digit
+        10u128.pow(len) + 1
+    } else {
+        mirror(half + 1, len)
+    }
+}
+
+/// The palindromes within `start..=end`, ascending, or `None` once there are more than `MAX_LEN`. Generation starts
+/// from the leading half of `start` and steps from each palindrome to the next, so the values in between, and the
+/// palindromes below `start`, are never visited.
+fn generate(start: u64, end: u64) -> Option<Vec<u64>> {
+    let mut found = Vec::new();
+    let mut p = next_palindrome(start as u128);
+    while p <= end as u128 {
+        found.push(p as u64);
+        if found.len() > MAX_LEN {
+            return None;
+        }
+        p = next_palindrome(p + 1);
+    }
+    Some(found)
+}
+
+/// Expand to a `&'static [Palindrome]` holding every palindrome within a range of integer literals, ascending, as in
+/// `palindromes!(1..=10_000)` or `palindromes!(100..1000)`.
+///
+/// The palindromes are generated while compiling and each is checked again by the constant evaluation of
+/// `Palindrome::new_const`, so the table costs nothing at startup. Ranges holding more than 2^20 palindromes are
+/// rejected.
+#[proc_macro]
+pub fn palindromes(input: TokenStream) -> TokenStream {
+    let bounds = parse_macro_input!(input as Bounds);
+    let values = match bounds.end {
+        Some(end) if end >= bounds.start => generate(bounds.start, end),
+        _ => Some(Vec::new()),
+    };
+    let Some(values) = values else {
+        let message = format!("palindromes! supports at most {MAX_LEN} palindromes");
+        return quote! { compile_error!(#message) }.into();
+    };
+
+    let values = values.into_iter().map(Literal::u64_suffixed);
+    quote! {{
+        const TABLE: &[::palindrome_products::Palindrome] = &[
+            #(::palindrome_products::Palindrome::new_const(#values).expect("palindromes! emits only palindromes")),*
+        ];
+        TABLE
+    }}
+    .into()
+}
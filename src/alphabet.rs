@@ -0,0 +1,57 @@
+//! Decimal digit alphabets beyond ASCII, for parsing and formatting palindromes in other scripts.
+
+/// A set of ten contiguous Unicode code points used to write the decimal digits zero through nine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DigitAlphabet {
+    /// `0123456789`
+    #[default]
+    Ascii,
+    /// `٠١٢٣٤٥٦٧٨٩`, as used with Arabic script.
+    ArabicIndic,
+    /// `۰۱۲۳۴۵۶۷۸۹`, as used with Persian and Urdu.
+    ExtendedArabicIndic,
+    /// `०१२३४५६७८९`
+    Devanagari,
+    /// `０１２３４５６７８９`, as used in CJK text.
+    Fullwidth,
+}
+
+impl DigitAlphabet {
+    /// Every supported alphabet.
+    pub const ALL: [DigitAlphabet; 5] = [
+        DigitAlphabet::Ascii,
+        DigitAlphabet::ArabicIndic,
+        DigitAlphabet::ExtendedArabicIndic,
+        DigitAlphabet::Devanagari,
+        DigitAlphabet::Fullwidth,
+    ];
+
+    fn zero(self) -> u32 {
+        match self {
+            DigitAlphabet::Ascii => '0' as u32,
+            DigitAlphabet::ArabicIndic => '\u{0660}' as u32,
+            DigitAlphabet::ExtendedArabicIndic => '\u{06F0}' as u32,
+            DigitAlphabet::Devanagari => '\u{0966}' as u32,
+            DigitAlphabet::Fullwidth => '\u{FF10}' as u32,
+        }
+    }
+
+    /// The value of `c` if it is a digit of this alphabet.
+    pub fn digit(self, c: char) -> Option<u8> {
+        let offset = (c as u32).checked_sub(self.zero())?;
+        (offset < 10).then_some(offset as u8)
+    }
+
+    /// The character for `digit` in this alphabet, or `None` if `digit` is not below ten.
+    pub fn char(self, digit: u8) -> Option<char> {
+        if digit >= 10 {
+            return None;
+        }
+        char::from_u32(self.zero() + digit as u32)
+    }
+
+    /// The alphabet which `c` is a digit of, if any.
+    pub fn detect(c: char) -> Option<DigitAlphabet> {
+        DigitAlphabet::ALL.into_iter().find(|alphabet| alphabet.digit(c).is_some())
+    }
+}
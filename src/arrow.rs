@@ -0,0 +1,58 @@
+//! Arrow record batches of search results, for columnar analytics without an intermediate text format.
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch, StringArray, UInt32Array, UInt64Array};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+
+use crate::{Palindrome, PalindromeProducts};
+
+/// The schema of [`PalindromeProducts::to_record_batch`]: one row per factor pair of each extreme.
+///
+/// | column | type | |
+/// |---|---|---|
+/// | `extreme` | `Utf8` | `smallest` or `largest` |
+/// | `palindrome` | `UInt64` | the palindromic product |
+/// | `factor_a` | `UInt64` | the smaller factor of the pair |
+/// | `factor_b` | `UInt64` | the larger factor of the pair |
+/// | `digits` | `UInt32` | the number of decimal digits of the palindrome |
+pub fn schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("extreme", DataType::Utf8, false),
+        Field::new("palindrome", DataType::UInt64, false),
+        Field::new("factor_a", DataType::UInt64, false),
+        Field::new("factor_b", DataType::UInt64, false),
+        Field::new("digits", DataType::UInt32, false),
+    ]))
+}
+
+impl PalindromeProducts {
+    /// Describe this result as an Arrow record batch following [`schema`]: the factor pairs of the smallest
+    /// palindrome, ascending by `factor_a`, then those of the largest. A range without palindromic products gives an
+    /// empty batch.
+    pub fn to_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+        let (mut extremes, mut palindromes, mut factors_a, mut factors_b, mut digits) =
+            (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        for (label, p) in [("smallest", self.smallest()), ("largest", self.largest())] {
+            let Some(p): Option<Palindrome> = p else {
+                continue;
+            };
+            for (a, b) in p.factors(self.min(), self.max()) {
+                extremes.push(label);
+                palindromes.push(p.into_inner());
+                factors_a.push(a);
+                factors_b.push(b);
+                digits.push(p.digits_in_base(10).len() as u32);
+            }
+        }
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(extremes)),
+            Arc::new(UInt64Array::from(palindromes)),
+            Arc::new(UInt64Array::from(factors_a)),
+            Arc::new(UInt64Array::from(factors_b)),
+            Arc::new(UInt32Array::from(digits)),
+        ];
+        RecordBatch::try_new(schema(), columns)
+    }
+}
@@ -0,0 +1,156 @@
+//! A small HTTP service around the search, built with the `server` feature.
+//!
+//! `GET /palindrome-products?min=10&max=99` answers with the report of
+//! [`PalindromeProducts::to_json`](palindrome_products::PalindromeProducts::to_json), and with `{"error": "..."}` and
+//! status 400 for missing or unparsable parameters and for empty ranges, where `min` is above `max`. Every search
+//! gets a deadline of the request timeout and gives up when it passes, answering 503, or as soon as the client goes
+//! away, so that abandoned requests free their thread. The search checks the deadline while testing each candidate
+//! too, so even a single slow factorization does not outlast it by much.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::ops::ControlFlow;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Json, Router};
+use palindrome_products::{Search, SearchError, SearchObserver, Strategy};
+use serde_json::{json, Value};
+
+const USAGE: &str = "\
+usage:
+    palindrome-products-server [--listen ADDR] [--timeout SECONDS]
+        serve GET /palindrome-products?min=N&max=N on ADDR (127.0.0.1:3000 by default),
+        giving up on searches which take longer than SECONDS (10 by default)";
+
+/// Stops a search once its deadline passes or its request is abandoned.
+struct Deadline {
+    at: Instant,
+    abandoned: Arc<AtomicBool>,
+}
+
+impl SearchObserver for Deadline {
+    fn on_candidate(&mut self, _product: u64) -> ControlFlow<()> {
+        self.on_tick()
+    }
+
+    fn on_tick(&mut self) -> ControlFlow<()> {
+        match self.abandoned.load(Ordering::Relaxed) || Instant::now() >= self.at {
+            true => ControlFlow::Break(()),
+            false => ControlFlow::Continue(()),
+        }
+    }
+}
+
+/// Raises its flag when dropped: when the handler holding it returns, or when the server drops the handler because
+/// the client went away.
+struct AbandonOnDrop(Arc<AtomicBool>);
+
+impl Drop for AbandonOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+type Response = (StatusCode, Json<Value>);
+
+fn error(status: StatusCode, message: impl ToString) -> Response {
+    (status, Json(json!({ "error": message.to_string() })))
+}
+
+/// The query parameter `name`, parsed as a factor.
+fn factor(query: &HashMap<String, String>, name: &str) -> Result<u64, Response> {
+    let value = query
+        .get(name)
+        .ok_or_else(|| error(StatusCode::BAD_REQUEST, format!("missing query parameter {name}")))?;
+    value
+        .parse()
+        .map_err(|_| error(StatusCode::BAD_REQUEST, format!("invalid value {value:?} for {name}")))
+}
+
+async fn palindrome_products(
+    State(timeout): State<Duration>,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let (min, max) = match (factor(&query, "min"), factor(&query, "max")) {
+        (Ok(min), Ok(max)) => (min, max),
+        (Err(response), _) | (_, Err(response)) => return response,
+    };
+    if min > max {
+        return error(StatusCode::BAD_REQUEST, format!("min {min} is above max {max}, so the range is empty"));
+    }
+
+    let abandoned = Arc::new(AtomicBool::new(false));
+    let _abandon = AbandonOnDrop(Arc::clone(&abandoned));
+    let mut deadline = Deadline { at: Instant::now() + timeout, abandoned };
+    let search = Search::new(min, max).strategy(Strategy::for_range(min, max));
+    match tokio::task::spawn_blocking(move || search.run_observed(&mut deadline)).await {
+        Ok(Ok(results)) => (StatusCode::OK, Json(results.to_json())),
+        Ok(Err(SearchError::Stopped)) => {
+            error(StatusCode::SERVICE_UNAVAILABLE, format!("the search took longer than {}s", timeout.as_secs_f64()))
+        }
+        Ok(Err(e)) => error(StatusCode::BAD_REQUEST, e),
+        Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+/// Parse the command line into the address to listen on and the request timeout.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(SocketAddr, Duration), String> {
+    let (mut listen, mut timeout) = (SocketAddr::from(([127, 0, 0, 1], 3000)), Duration::from_secs(10));
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--listen" => {
+                let value = value()?;
+                listen = value.parse().map_err(|_| format!("invalid address {value:?} for --listen"))?;
+            }
+            "--timeout" => {
+                let value = value()?;
+                timeout = value
+                    .parse()
+                    .ok()
+                    .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                    .ok_or_else(|| format!("invalid value {value:?} for --timeout"))?;
+            }
+            _ => return Err(format!("unknown argument {arg:?}")),
+        }
+    }
+    Ok((listen, timeout))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let (listen, timeout) = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let listener = match tokio::net::TcpListener::bind(listen).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("error: cannot listen on {listen}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    // with port zero, this is the only way to learn which port was picked
+    if let Ok(address) = listener.local_addr() {
+        eprintln!("listening on {address}");
+    }
+
+    let app = Router::new().route("/palindrome-products", get(palindrome_products)).with_state(timeout);
+    match axum::serve(listener, app).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Palindromes in base two.
+
+use std::ops::RangeInclusive;
+
+use crate::radix::RadixPalindromes;
+
+/// `BinaryPalindrome` is a newtype which only exists when the contained value is a palindrome number in base two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BinaryPalindrome(u64);
+
+impl BinaryPalindrome {
+    /// Create a `BinaryPalindrome` only if `value` is in fact a palindrome when represented in base two. Otherwise,
+    /// `None`.
+    pub fn new(value: u64) -> Option<BinaryPalindrome> {
+        // reversing all 64 bits moves the leading zeros to the bottom, which the shift then discards
+        let is_palindrome = value == 0 || value.reverse_bits() >> value.leading_zeros() == value;
+        is_palindrome.then_some(BinaryPalindrome(value))
+    }
+
+    /// Iterate over the binary palindromes within `range`, ascending from the front and descending from the back,
+    /// constructing each one from its leading bits rather than testing every value.
+    pub fn in_range(range: RangeInclusive<u64>) -> BinaryPalindromes {
+        BinaryPalindromes(RadixPalindromes::new(*range.start(), *range.end(), 2))
+    }
+
+    /// Get the value of this palindrome.
+    pub fn into_inner(self) -> u64 {
+        self.0
+    }
+}
+
+/// Iterator returned by [`BinaryPalindrome::in_range`].
+#[derive(Debug, Clone)]
+pub struct BinaryPalindromes(RadixPalindromes);
+
+impl Iterator for BinaryPalindromes {
+    type Item = BinaryPalindrome;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(BinaryPalindrome)
+    }
+}
+
+impl DoubleEndedIterator for BinaryPalindromes {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(BinaryPalindrome)
+    }
+}
+
+/// Find the smallest and largest binary palindromes which are products of two factors within `min..=max`.
+pub fn binary_palindrome_products(min: u64, max: u64) -> Option<(BinaryPalindrome, BinaryPalindrome)> {
+    crate::extreme_products(min, max, BinaryPalindrome::new)
+}
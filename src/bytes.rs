@@ -0,0 +1,40 @@
+//! Palindromes over the big-endian byte representation of an integer.
+
+/// Number of bytes needed to represent `n`, ignoring leading zero bytes. Zero still occupies one byte.
+fn significant_bytes(n: u64) -> u32 {
+    (u64::BITS - n.leading_zeros()).div_ceil(8).max(1)
+}
+
+/// Whether the big-endian bytes of `n` read the same in both directions.
+///
+/// With `significant_only` set, leading zero bytes are ignored, so `0x00_00_12_34_12` is checked as
+/// `[0x12, 0x34, 0x12]`. Otherwise all eight bytes take part and the value must mirror across its full width.
+pub fn is_byte_palindrome(n: u64, significant_only: bool) -> bool {
+    if significant_only {
+        let len = significant_bytes(n);
+        n.swap_bytes() >> (8 * (8 - len)) == n
+    } else {
+        n.swap_bytes() == n
+    }
+}
+
+/// `BytePalindrome` is a newtype which only exists when the big-endian bytes of the contained value are a palindrome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BytePalindrome(u64);
+
+impl BytePalindrome {
+    /// Create a `BytePalindrome` only if all eight bytes of `value` mirror themselves. Otherwise, `None`.
+    pub fn new(value: u64) -> Option<BytePalindrome> {
+        is_byte_palindrome(value, false).then_some(BytePalindrome(value))
+    }
+
+    /// Create a `BytePalindrome` only if the significant bytes of `value` mirror themselves. Otherwise, `None`.
+    pub fn new_significant(value: u64) -> Option<BytePalindrome> {
+        is_byte_palindrome(value, true).then_some(BytePalindrome(value))
+    }
+
+    /// Get the value of this palindrome.
+    pub fn into_inner(self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,66 @@
+//! Palindromic calendar dates, such as 2021-12-02 written as `20211202`.
+
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, NaiveDate};
+
+/// How a date is written as a string of eight digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateFormat {
+    /// Year, month, day: `20211202`.
+    YearMonthDay,
+    /// Day, month, year: `02022020`.
+    DayMonthYear,
+    /// Month, day, year: `12022021`.
+    MonthDayYear,
+}
+
+impl DateFormat {
+    /// Every supported format.
+    pub const ALL: [DateFormat; 3] = [DateFormat::YearMonthDay, DateFormat::DayMonthYear, DateFormat::MonthDayYear];
+
+    /// Write `date` as eight digits, or `None` if its year does not have exactly four digits.
+    fn digits(self, date: NaiveDate) -> Option<String> {
+        let (y, m, d) = (date.year(), date.month(), date.day());
+        if !(0..=9999).contains(&y) {
+            return None;
+        }
+
+        Some(match self {
+            DateFormat::YearMonthDay => format!("{y:04}{m:02}{d:02}"),
+            DateFormat::DayMonthYear => format!("{d:02}{m:02}{y:04}"),
+            DateFormat::MonthDayYear => format!("{m:02}{d:02}{y:04}"),
+        })
+    }
+
+    /// The only date in `year` which can be a palindrome in this format, if it exists on the calendar.
+    ///
+    /// The four digits of the year fix the other four digits by mirroring, so each year has at most one
+    /// palindromic date per format.
+    fn palindrome_in(self, year: i32) -> Option<NaiveDate> {
+        if !(0..=9999).contains(&year) {
+            return None;
+        }
+
+        let mirrored: u32 = format!("{year:04}").chars().rev().collect::<String>().parse().ok()?;
+        let (first, second) = (mirrored / 100, mirrored % 100);
+        match self {
+            DateFormat::YearMonthDay | DateFormat::MonthDayYear => NaiveDate::from_ymd_opt(year, first, second),
+            DateFormat::DayMonthYear => NaiveDate::from_ymd_opt(year, second, first),
+        }
+    }
+}
+
+/// Whether `date` reads the same in both directions when written in `format`.
+pub fn is_palindromic_date(date: NaiveDate, format: DateFormat) -> bool {
+    format
+        .digits(date)
+        .is_some_and(|digits| digits.bytes().eq(digits.bytes().rev()))
+}
+
+/// Iterate in ascending order over the palindromic dates in `format` whose year lies within `years`.
+///
+/// Only years `0..=9999` can produce an eight digit date; the rest of the range yields nothing.
+pub fn palindromic_dates(years: RangeInclusive<i32>, format: DateFormat) -> impl Iterator<Item = NaiveDate> {
+    years.filter_map(move |year| format.palindrome_in(year))
+}
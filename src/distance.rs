@@ -0,0 +1,80 @@
+//! How far numbers are from being palindromes, counted in digit edits.
+
+use crate::{radix, DigitBuf, Palindrome};
+
+/// The pairs of digit positions `(low, high)` which differ in `digits`, least significant first.
+fn mismatches(digits: &[u8]) -> Vec<(usize, usize)> {
+    let len = digits.len();
+    (0..len / 2).map(|low| (low, len - 1 - low)).filter(|&(low, high)| digits[low] != digits[high]).collect()
+}
+
+/// The minimum number of digit replacements which turn the decimal digits of `n` into a palindrome of the same
+/// length: one for each pair of mirrored digits which differ, as `1234` needs two.
+pub fn palindrome_distance(n: u64) -> u32 {
+    mismatches(DigitBuf::new().fill(n)).len() as u32
+}
+
+/// A palindrome which is [`palindrome_distance`] replacements away from `n`, choosing the one nearest to `n` in value
+/// and the smaller of two equally near. A palindrome is its own nearest.
+///
+/// Making one digit of each differing pair match the other never needs more replacements, so every candidate comes
+/// from such a choice: `1234` is nearest to `1221`, and `19` to `11` rather than `99`.
+pub fn nearest_palindrome(n: u64) -> Palindrome {
+    let mut buf = DigitBuf::new();
+    let digits = buf.fill(n);
+    let pairs = mismatches(digits);
+    let len = digits.len();
+
+    let mut nearest: Option<(u128, u64)> = None;
+    let mut candidate = digits.to_vec();
+    // at most ten pairs of a twenty digit number, so at most 1024 choices
+    for choice in 0..1u32 << pairs.len() {
+        for (bit, &(low, high)) in pairs.iter().enumerate() {
+            match choice >> bit & 1 {
+                0 => (candidate[low], candidate[high]) = (digits[high], digits[high]),
+                _ => (candidate[low], candidate[high]) = (digits[low], digits[low]),
+            }
+        }
+        if len > 1 && candidate[len - 1] == 0 {
+            continue;
+        }
+        let value = candidate.iter().rev().fold(0u128, |value, &d| value * 10 + d as u128);
+        let Ok(value) = u64::try_from(value) else {
+            continue;
+        };
+        let distance = n.abs_diff(value) as u128;
+        if nearest.is_none_or(|(best, best_value)| (distance, value) < (best, best_value)) {
+            nearest = Some((distance, value));
+        }
+    }
+
+    // taking the smaller digit of each pair, or the leading one, always gives a value no larger than n + 1, which
+    // fits because n then ends in zero
+    debug_assert!(nearest.is_some(), "no choice for {n} fits in a u64");
+    Palindrome(nearest.map_or_else(|| radix::prev_palindrome(n, 10), |(_, value)| value))
+}
+
+/// Whether at most `k` digit edits turn the decimal digits of `n` into a palindrome, where an edit either removes a
+/// digit or replaces it: `12341` needs one removal and `4321` two replacements.
+///
+/// This generalizes the check of [`palindrome_distance`], which only replaces digits, so it never needs more edits.
+pub fn is_almost_palindrome(n: u64, k: u32) -> bool {
+    let mut buf = DigitBuf::new();
+    let digits = buf.fill(n);
+    let len = digits.len();
+
+    // edits[i][j] is the fewest edits for the digits i..=j, filled for ever longer spans; spans of at most one digit
+    // need none
+    let mut edits = [[0u32; 20]; 20];
+    for span in 2..=len {
+        for i in 0..=len - span {
+            let j = i + span - 1;
+            let inner = if span == 2 { 0 } else { edits[i + 1][j - 1] };
+            edits[i][j] = match digits[i] == digits[j] {
+                true => inner,
+                false => 1 + inner.min(edits[i + 1][j]).min(edits[i][j - 1]),
+            };
+        }
+    }
+    edits[0][len - 1] <= k
+}
@@ -0,0 +1,238 @@
+//! Error types returned by the fallible constructors.
+
+use std::error::Error;
+use std::fmt;
+
+/// Why a string could not be parsed into a [`Palindrome`](crate::Palindrome).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParseError {
+    /// The input contained no digits.
+    Empty,
+    /// The input contained a character which is not a digit of the expected alphabet.
+    InvalidDigit(char),
+    /// The parsed number does not fit in a `u64`.
+    Overflow,
+    /// The parsed number is not a palindrome.
+    NotPalindrome(NotPalindrome),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "cannot parse a palindrome from an empty string"),
+            ParseError::InvalidDigit(c) => write!(f, "invalid digit {c:?}"),
+            ParseError::Overflow => write!(f, "number too large to fit in a u64"),
+            ParseError::NotPalindrome(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::NotPalindrome(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<NotPalindrome> for ParseError {
+    fn from(e: NotPalindrome) -> Self {
+        ParseError::NotPalindrome(e)
+    }
+}
+
+/// Why a slice of digits could not be assembled into a [`Palindrome`](crate::Palindrome) by
+/// [`Palindrome::from_digits`](crate::Palindrome::from_digits).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FromDigitsError {
+    /// There were no digits.
+    Empty,
+    /// The digit at `index` is not below ten.
+    InvalidDigit { index: usize, digit: u8 },
+    /// The first of several digits is zero.
+    LeadingZero,
+    /// The number does not fit in a `u64`.
+    Overflow,
+    /// The number is not a palindrome.
+    NotPalindrome(NotPalindrome),
+}
+
+impl fmt::Display for FromDigitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromDigitsError::Empty => write!(f, "cannot assemble a palindrome from no digits"),
+            FromDigitsError::InvalidDigit { index, digit } => write!(f, "invalid digit {digit} at index {index}"),
+            FromDigitsError::LeadingZero => write!(f, "leading zero"),
+            FromDigitsError::Overflow => write!(f, "number too large to fit in a u64"),
+            FromDigitsError::NotPalindrome(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for FromDigitsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FromDigitsError::NotPalindrome(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<NotPalindrome> for FromDigitsError {
+    fn from(e: NotPalindrome) -> Self {
+        FromDigitsError::NotPalindrome(e)
+    }
+}
+
+/// A value rejected by [`Palindrome::try_new`](crate::Palindrome::try_new), together with its digit reversal to show
+/// where the two differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NotPalindrome {
+    value: u64,
+    reversed: u128,
+    base: u32,
+}
+
+impl NotPalindrome {
+    pub(crate) fn new(value: u64) -> NotPalindrome {
+        NotPalindrome::in_base(value, 10)
+    }
+
+    pub(crate) fn in_base(value: u64, base: u32) -> NotPalindrome {
+        NotPalindrome { value, reversed: crate::radix::reverse(value as u128, base as u128), base }
+    }
+
+    /// The value which is not a palindrome.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// The value with its digits in [`base`](NotPalindrome::base) reversed. Reversing a large `u64` can exceed
+    /// `u64::MAX`, hence the wider type.
+    pub fn reversed(&self) -> u128 {
+        self.reversed
+    }
+
+    /// The base in which the value was checked, ten unless it came from
+    /// [`Palindrome::value_from_str_radix`](crate::Palindrome::value_from_str_radix).
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+}
+
+impl fmt::Display for NotPalindrome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.base {
+            10 => write!(f, "{} is not a palindrome: it reads {} backwards", self.value, self.reversed),
+            base => write!(
+                f,
+                "{} is not a palindrome in base {base}: it reads {} backwards",
+                self.value, self.reversed
+            ),
+        }
+    }
+}
+
+impl Error for NotPalindrome {}
+
+/// Why shard results could not be merged by [`merge_shard_results`](crate::merge_shard_results).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MergeError {
+    /// There were no results to merge.
+    NoShards,
+    /// The shards belong to searches with different minimum factors.
+    MismatchedMin { expected: u64, found: u64 },
+    /// The shards do not cover one contiguous range: a shard is missing or two overlap.
+    NotContiguous { expected_from: u64, found_from: u64 },
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeError::NoShards => write!(f, "no shard results to merge"),
+            MergeError::MismatchedMin { expected, found } => {
+                write!(f, "shard searches minimum factor {found}, expected {expected}")
+            }
+            MergeError::NotContiguous { expected_from, found_from } => {
+                write!(f, "expected a shard starting at {expected_from}, found one starting at {found_from}")
+            }
+        }
+    }
+}
+
+impl Error for MergeError {}
+
+/// Why a JSON value could not be read back into a [`ShardResult`](crate::ShardResult) by
+/// [`ShardResult::from_json`](crate::ShardResult::from_json).
+#[cfg(feature = "serde_json")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ShardJsonError {
+    /// The value follows a version of the schema this crate does not understand.
+    UnsupportedVersion(u64),
+    /// A field is absent or does not hold the expected kind of value.
+    InvalidField(&'static str),
+    /// A reported extreme is not a palindrome.
+    NotPalindrome(NotPalindrome),
+}
+
+#[cfg(feature = "serde_json")]
+impl fmt::Display for ShardJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShardJsonError::UnsupportedVersion(version) => write!(f, "unsupported shard schema version {version}"),
+            ShardJsonError::InvalidField(field) => write!(f, "missing or invalid field {field:?}"),
+            ShardJsonError::NotPalindrome(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl Error for ShardJsonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ShardJsonError::NotPalindrome(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<NotPalindrome> for ShardJsonError {
+    fn from(e: NotPalindrome) -> Self {
+        ShardJsonError::NotPalindrome(e)
+    }
+}
+
+/// Why a [`Search`](crate::Search) could not run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SearchError {
+    /// The chosen strategy needs more working memory than the configured limit allows.
+    MemoryLimit {
+        /// Estimated bytes the strategy needs for this range.
+        required: usize,
+        /// The configured limit in bytes.
+        limit: usize,
+    },
+    /// A [`SearchObserver`](crate::SearchObserver) stopped the search early.
+    Stopped,
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchError::MemoryLimit { required, limit } => {
+                write!(f, "search needs about {required} bytes of working memory, more than the limit of {limit}")
+            }
+            SearchError::Stopped => write!(f, "search stopped by its observer"),
+        }
+    }
+}
+
+impl Error for SearchError {}
@@ -0,0 +1,61 @@
+//! Iterator adaptors for finding palindromes in arbitrary sequences of numbers.
+
+use std::iter::{Filter, FilterMap};
+
+use crate::Palindrome;
+
+/// Adaptors available on every iterator of `u64`s.
+///
+/// ```
+/// use palindrome_products::PalindromeExt;
+///
+/// let squares: Vec<u64> = (1..30u64).map(|n| n * n).filter_palindromic().collect();
+/// assert_eq!(squares, [1, 4, 9, 121, 484, 676]);
+/// ```
+pub trait PalindromeExt: Iterator<Item = u64> + Sized {
+    /// Keep the values which are base ten palindromes, as [`Palindrome`]s.
+    fn palindromes(self) -> FilterMap<Self, fn(u64) -> Option<Palindrome>> {
+        self.filter_map(Palindrome::new)
+    }
+
+    /// Keep the values which are base ten palindromes, as plain `u64`s.
+    fn filter_palindromic(self) -> Filter<Self, fn(&u64) -> bool> {
+        self.filter(|&value| Palindrome::new(value).is_some())
+    }
+}
+
+impl<I: Iterator<Item = u64>> PalindromeExt for I {}
+
+/// Adaptors available on every iterator of factor pairs.
+///
+/// ```
+/// use palindrome_products::FactorPairsExt;
+///
+/// let pairs = [(91, 99), (90, 99), (11, 11)];
+/// let products: Vec<_> = pairs.into_iter().palindrome_products().map(|(p, pair)| (p.into_inner(), pair)).collect();
+/// assert_eq!(products, [(9009, (91, 99)), (121, (11, 11))]);
+/// ```
+pub trait FactorPairsExt: Iterator<Item = (u64, u64)> + Sized {
+    /// Multiply each pair, skipping pairs whose product overflows, and keep the palindromic products together with
+    /// the pair which produced them.
+    fn palindrome_products(self) -> PairProducts<Self> {
+        PairProducts(self)
+    }
+}
+
+impl<I: Iterator<Item = (u64, u64)>> FactorPairsExt for I {}
+
+/// Iterator over the palindromic products of factor pairs, returned by [`FactorPairsExt::palindrome_products`].
+#[derive(Debug, Clone)]
+pub struct PairProducts<I>(I);
+
+impl<I: Iterator<Item = (u64, u64)>> Iterator for PairProducts<I> {
+    type Item = (Palindrome, (u64, u64));
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.find_map(|(a, b)| Some((Palindrome::new(a.checked_mul(b)?)?, (a, b))))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
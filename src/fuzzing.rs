@@ -0,0 +1,56 @@
+//! `Arbitrary` implementations which generate structurally valid inputs for fuzz targets.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{radix, Palindrome, Search, Strategy};
+
+/// Widest factor range of an arbitrary [`Search`], so that every generated search finishes quickly.
+const MAX_WIDTH: u64 = 1000;
+
+impl<'a> Arbitrary<'a> for Palindrome {
+    /// Mirror arbitrary leading digits into a palindrome of arbitrary length, rather than filtering random integers.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len: u32 = u.int_in_range(1..=20)?;
+        let half_len = len.div_ceil(2);
+        let smallest_half = match len {
+            1 => 0,
+            _ => 10u128.pow(half_len - 1),
+        };
+        let half = u.int_in_range(smallest_half..=10u128.pow(half_len) - 1)?;
+        let value = radix::mirror(half, len, 10);
+        // twenty digit palindromes above u64::MAX fall back to the largest one which fits
+        Ok(Palindrome(u64::try_from(value).unwrap_or(radix::prev_palindrome(u64::MAX, 10))))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(1 + size_of::<u128>()))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Strategy {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&Strategy::ALL).copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for Search {
+    /// A search over at most a thousand factors, with options which keep it meaningful: the step and exclusions
+    /// always leave factors which lie in the range.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let min: u64 = u.arbitrary()?;
+        let max = min.saturating_add(u.int_in_range(0..=MAX_WIDTH)?);
+        let mut search = Search::new(min, max).palindromic_factors(u.arbitrary()?).step(u.int_in_range(1..=16)?);
+        search = search.seed(u.arbitrary()?).min_digits(u.int_in_range(0..=4)?);
+
+        if let Some(strategy) = u.arbitrary::<Option<Strategy>>()? {
+            search = search.strategy(strategy);
+        }
+        if u.arbitrary()? {
+            search = search.max_memory(u.int_in_range(0..=1 << 20)?);
+        }
+        let excluded = (0..u.int_in_range(0..=8)?)
+            .map(|_| u.int_in_range(min..=max))
+            .collect::<Result<Vec<u64>>>()?;
+        Ok(search.exclude_factors(&excluded))
+    }
+}
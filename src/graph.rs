@@ -0,0 +1,157 @@
+//! Recording a search as a graph, for rendering how the traversal narrows the candidates.
+
+use std::fmt::Write;
+use std::ops::ControlFlow;
+
+use crate::{Palindrome, PruneReason, SearchObserver};
+
+/// What happened to the product of a [`GraphNode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeKind {
+    /// The product was tested and is not a palindrome, or not one with accepted factors.
+    Candidate,
+    /// The product was skipped without testing.
+    Pruned(PruneReason),
+    /// The product is a palindrome which improved on the best so far, with its factor pairs.
+    Found(Vec<(u64, u64)>),
+}
+
+/// One product visited by the search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphNode {
+    product: u64,
+    kind: NodeKind,
+}
+
+impl GraphNode {
+    /// The product or palindrome this node stands for.
+    pub fn product(&self) -> u64 {
+        self.product
+    }
+
+    /// What the search did with the product.
+    pub fn kind(&self) -> &NodeKind {
+        &self.kind
+    }
+}
+
+/// A [`SearchObserver`] which records every event of a search as a graph.
+///
+/// Each tested product is a node linked to the one tested before it, so the candidates form the path of the
+/// traversal. Pruned products hang off the candidate tested last before them. The graph grows with every product
+/// the search visits, so it is meant for small ranges.
+///
+/// ```
+/// use palindrome_products::{Search, SearchGraph};
+///
+/// let mut graph = SearchGraph::new();
+/// Search::new(10, 20).run_observed(&mut graph).unwrap();
+/// assert!(graph.to_dot().starts_with("digraph search {"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SearchGraph {
+    nodes: Vec<GraphNode>,
+    edges: Vec<(usize, usize)>,
+    last_candidate: Option<usize>,
+}
+
+impl SearchGraph {
+    /// An empty graph.
+    pub fn new() -> SearchGraph {
+        SearchGraph::default()
+    }
+
+    /// Every node, in the order the search visited them.
+    pub fn nodes(&self) -> &[GraphNode] {
+        &self.nodes
+    }
+
+    /// Every edge as the indices of its two nodes in [`nodes`](SearchGraph::nodes).
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// Render the graph in the Graphviz DOT language. Found palindromes are filled and pruned products are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph search {\n    node [shape=box];\n");
+        for (id, node) in self.nodes.iter().enumerate() {
+            let product = node.product;
+            let _ = match &node.kind {
+                NodeKind::Candidate => writeln!(dot, "    n{id} [label=\"{product}\"];"),
+                NodeKind::Pruned(reason) => {
+                    writeln!(dot, "    n{id} [label=\"{product}\\n{reason}\", style=dashed];")
+                }
+                NodeKind::Found(factors) => writeln!(
+                    dot,
+                    "    n{id} [label=\"{product}\\n{}\", style=filled, fillcolor=palegreen];",
+                    format_factors(factors)
+                ),
+            };
+        }
+        for &(from, to) in &self.edges {
+            let _ = match self.nodes[to].kind {
+                NodeKind::Pruned(_) => writeln!(dot, "    n{from} -> n{to} [style=dashed];"),
+                _ => writeln!(dot, "    n{from} -> n{to};"),
+            };
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Render the graph as JSON: an object with a `nodes` array of `{id, product, kind}` objects, where pruned nodes
+    /// add a `reason` and found nodes their `factors` as `[a, b]` pairs, and an `edges` array of `[from, to]` pairs.
+    pub fn to_json(&self) -> String {
+        let nodes: Vec<String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(id, node)| {
+                let fields = match &node.kind {
+                    NodeKind::Candidate => r#""kind":"candidate""#.to_string(),
+                    NodeKind::Pruned(reason) => format!(r#""kind":"pruned","reason":"{reason}""#),
+                    NodeKind::Found(factors) => {
+                        let pairs: Vec<String> = factors.iter().map(|(a, b)| format!("[{a},{b}]")).collect();
+                        format!(r#""kind":"found","factors":[{}]"#, pairs.join(","))
+                    }
+                };
+                format!(r#"{{"id":{id},"product":{},{fields}}}"#, node.product)
+            })
+            .collect();
+        let edges: Vec<String> = self.edges.iter().map(|(from, to)| format!("[{from},{to}]")).collect();
+        format!(r#"{{"nodes":[{}],"edges":[{}]}}"#, nodes.join(","), edges.join(","))
+    }
+
+    fn push(&mut self, product: u64, kind: NodeKind) -> usize {
+        let id = self.nodes.len();
+        self.nodes.push(GraphNode { product, kind });
+        if let Some(from) = self.last_candidate {
+            self.edges.push((from, id));
+        }
+        id
+    }
+}
+
+fn format_factors(factors: &[(u64, u64)]) -> String {
+    factors.iter().map(|(a, b)| format!("{a} × {b}")).collect::<Vec<_>>().join(", ")
+}
+
+impl SearchObserver for SearchGraph {
+    fn on_candidate(&mut self, product: u64) -> ControlFlow<()> {
+        self.last_candidate = Some(self.push(product, NodeKind::Candidate));
+        ControlFlow::Continue(())
+    }
+
+    fn on_pruned(&mut self, product: u64, reason: PruneReason) {
+        self.push(product, NodeKind::Pruned(reason));
+    }
+
+    fn on_palindrome_found(&mut self, p: Palindrome, factors: &[(u64, u64)]) {
+        // the palindrome is the candidate tested last
+        match self.last_candidate.map(|id| &mut self.nodes[id]) {
+            Some(node) if node.product == p.into_inner() => node.kind = NodeKind::Found(factors.to_vec()),
+            _ => {
+                self.push(p.into_inner(), NodeKind::Found(factors.to_vec()));
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! JSON reports of search results.
+
+use serde_json::{json, Value};
+
+use crate::{Palindrome, PalindromeProducts, Shard, ShardJsonError, ShardResult};
+
+/// Version of the schemas produced by [`PalindromeProducts::to_json`] and [`ShardResult::to_json`]. It changes only
+/// when existing fields change meaning or disappear.
+pub const SCHEMA_VERSION: u64 = 1;
+
+impl PalindromeProducts {
+    /// Describe this result as JSON, following version 1 of the schema:
+    ///
+    /// ```text
+    /// {
+    ///   "schema_version": 1,
+    ///   "min": 10,                        // smallest factor of the range
+    ///   "max": 99,                        // largest factor of the range
+    ///   "smallest": {                     // null if there are no palindromic products
+    ///     "value": 121,
+    ///     "factors": [[11, 11]]           // every pair (a, b) with a <= b, ascending by a
+    ///   },
+    ///   "largest": { "value": 9009, "factors": [[91, 99]] },
+    ///   "metrics": {
+    ///     "factors": 90,                  // number of factors in the range
+    ///     "factor_pairs": 4095            // number of pairs (a, b) with a <= b
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// All numbers are unsigned 64-bit integers, which exceed the exact range of a JavaScript `number`; consumers
+    /// should parse them as such.
+    pub fn to_json(&self) -> Value {
+        let (min, max) = (self.min(), self.max());
+        let extreme = |p: Option<Palindrome>| {
+            p.map(|p| {
+                let factors: Vec<[u64; 2]> = p.factors(min, max).into_iter().map(|(a, b)| [a, b]).collect();
+                json!({ "value": p.into_inner(), "factors": factors })
+            })
+        };
+
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "min": min,
+            "max": max,
+            "smallest": extreme(self.smallest()),
+            "largest": extreme(self.largest()),
+            "metrics": {
+                "factors": self.factor_count(),
+                "factor_pairs": self.factor_pair_count(),
+            },
+        })
+    }
+}
+
+impl ShardResult {
+    /// Describe this shard result as JSON, following version 1 of the schema, so that shards searched by separate
+    /// jobs can be stored and merged later:
+    ///
+    /// ```text
+    /// {
+    ///   "schema_version": 1,
+    ///   "shard": { "min": 10, "from": 10, "to": 54 },
+    ///   "smallest": 121,                  // null if the shard has no palindromic products
+    ///   "largest": 2332
+    /// }
+    /// ```
+    pub fn to_json(&self) -> Value {
+        let shard = self.shard();
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "shard": { "min": shard.min(), "from": shard.from(), "to": shard.to() },
+            "smallest": self.smallest().map(Palindrome::into_inner),
+            "largest": self.largest().map(Palindrome::into_inner),
+        })
+    }
+
+    /// Read back a shard result written by [`ShardResult::to_json`].
+    pub fn from_json(value: &Value) -> Result<ShardResult, ShardJsonError> {
+        let number = |value: &Value, field| value.as_u64().ok_or(ShardJsonError::InvalidField(field));
+        let version = number(&value["schema_version"], "schema_version")?;
+        if version != SCHEMA_VERSION {
+            return Err(ShardJsonError::UnsupportedVersion(version));
+        }
+
+        let shard = |field| number(&value["shard"][field], field);
+        let (min, from, to) = (shard("min")?, shard("from")?, shard("to")?);
+        let extreme = |field| -> Result<Option<Palindrome>, ShardJsonError> {
+            match &value[field] {
+                Value::Null => Ok(None),
+                p => Ok(Some(Palindrome::try_new(number(p, field)?)?)),
+            }
+        };
+        Ok(ShardResult::new(Shard::new(min, from, to), extreme("smallest")?, extreme("largest")?))
+    }
+}
@@ -1,57 +1,248 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+pub mod alphabet;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod binary;
+pub mod bytes;
+#[cfg(feature = "chrono")]
+pub mod dates;
+mod distance;
+mod error;
+mod ext;
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+mod graph;
+#[cfg(feature = "serde_json")]
+pub mod json;
+#[cfg(feature = "mmap")]
+mod mapped;
+mod narrow;
+mod observer;
+mod palindromic;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "primes")]
+pub mod primes;
+mod products;
+mod radix;
+mod random;
+#[cfg(feature = "reference")]
+pub mod reference;
+pub mod report;
+pub mod roman;
+pub mod sequences;
+mod search;
+mod set;
+mod shard;
+mod strategy;
+mod stream;
+mod system;
+mod table;
+pub mod text;
+mod warm;
+mod witness;
+
+use radix::RadixPalindromes;
+use warm::CachedPalindromes;
+
+pub use alphabet::DigitAlphabet;
+pub use binary::BinaryPalindrome;
+pub use bytes::BytePalindrome;
+pub use distance::{is_almost_palindrome, nearest_palindrome, palindrome_distance};
+pub use error::{FromDigitsError, MergeError, NotPalindrome, ParseError, SearchError};
+#[cfg(feature = "serde_json")]
+pub use error::ShardJsonError;
+pub use ext::{FactorPairsExt, PairProducts, PalindromeExt};
+pub use graph::{GraphNode, NodeKind, SearchGraph};
+pub use products::{
+    checked_sum_of_palindrome_products, count_palindrome_products, count_unreachable_palindromes, digit_range,
+    extend_max, factor_pairs, median_palindrome_product, nth_palindrome_product, palindrome_product_map,
+    smallest_with_k_representations, sum_of_palindrome_products, unreachable_palindromes, PalindromeProducts,
+};
+#[cfg(feature = "mmap")]
+pub use mapped::MappedPalindromeSet;
+pub use narrow::palindrome_products_u32;
+pub use observer::{PruneReason, SearchObserver};
+#[cfg(feature = "macros")]
+pub use palindrome_products_macros::palindromes;
+pub use palindromic::Palindromic;
+pub use radix::{reverse_digits_in_base, DigitBuf, DigitOrder, Digits};
+pub use search::{Search, DEFAULT_SEED};
+pub use set::PalindromeSet;
+pub use shard::{merge_shard_results, shard_range, Shard, ShardResult};
+pub use strategy::{palindrome_products_with, Strategy};
+pub use stream::StreamChecker;
+pub use system::{BalancedTernary, DigitSystem, FixedWidth, Grouped, Radix};
+pub use warm::{is_warmed_up, warm_up, warmed_up_digits};
+pub use witness::{Claim, Witness};
 
 /// `Palindrome` is a newtype which only exists when the contained value is a palindrome number in base ten.
 ///
 /// A struct with a single field which is used to constrain behavior like this is called a "newtype", and its use is
 /// often referred to as the "newtype pattern". This is a fairly common pattern in Rust.
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Palindrome(u64);
 
-struct ProductRange {
+/// Iterator over the distinct products of two factors within `min..=max`, in ascending order from the front and
+/// descending order from the back.
+///
+/// Each factor `k` of the range is a row holding the next product of `k` to visit. Iterating from the front, a row
+/// steps through `k * k, k * (k + 1), .., k * max`; from the back, through `k * k, k * (k - 1), .., k * min`. The
+/// values last returned from each end act as fences: rows which cross the opposite fence are dropped, so both ends
+/// can be consumed without overlapping. Products which do not fit in a `u64` are skipped.
+///
+/// Rows are created lazily, from the low end of the range once the front reaches their square and from the high end
+/// once the back falls to it, so memory grows with the rows in play rather than with the width of the range.
+#[derive(Debug, Clone)]
+pub struct SortedProducts {
     min: u64,
     max: u64,
     last_min: Option<u64>,
     last_max: Option<u64>,
     data: HashMap<u64, u64>,
+    /// The factors whose rows have not been created yet.
+    unseeded: RangeInclusive<u64>,
 }
 
-impl ProductRange {
-    pub fn new(min: u64, max: u64) -> ProductRange {
-        ProductRange { 
+impl SortedProducts {
+    /// Enumerate the products of two factors within `min..=max`.
+    pub fn new(min: u64, max: u64) -> SortedProducts {
+        SortedProducts { 
             min, 
             max, 
             last_min: None,
             last_max: None,
-            data: HashMap::from_iter((min..=max).map(|i| (i, i * i) )) }
+            data: HashMap::new(),
+            unseeded: min..=max }
     }
 
-    fn find_keys(&self, value: u64) -> Vec<u64> {
-        self.data.iter()
-            .filter_map(|(k, &v)| if v == value {Some(k)} else {None} )
-            .cloned()
-            .collect()
+    /// Create the rows from the low end of the range whose squares do not exceed the smallest pending product, so
+    /// that it is the smallest product left. Rows whose square overflows are left to the back, as the products of
+    /// their partners from the front are visited in the rows of those partners.
+    fn seed_front(&mut self) {
+        let mut smallest = self.data.values().copied().min();
+        while let Some(square) = self.unseeded_front().and_then(|k| k.checked_mul(k)) {
+            if smallest.is_some_and(|v| square > v) || self.last_max.is_some_and(|m| square >= m) {
+                break;
+            }
+            if let Some(k) = self.unseeded.next() {
+                self.data.insert(k, square);
+            }
+            smallest = Some(smallest.map_or(square, |v| v.min(square)));
+        }
     }
-}
 
-impl DoubleEndedIterator for ProductRange {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.data.is_empty() {
-            return None;
+    /// Create the rows from the high end of the range whose first products may reach the largest pending product, so
+    /// that it is the largest product left.
+    fn seed_back(&mut self) {
+        let mut largest = self.data.values().copied().max();
+        while let Some(k) = self.unseeded_back() {
+            // the first product of each row is at most its square, which shrinks towards the low end
+            let bound = k.saturating_mul(k);
+            if largest.is_some_and(|v| bound < v) || self.last_min.is_some_and(|m| bound <= m) {
+                break;
+            }
+            self.unseeded.next_back();
+            if let Some(first) = SortedProducts::first_product(k, self.min) {
+                self.data.insert(k, first);
+                largest = Some(largest.map_or(first, |v| v.max(first)));
+            }
         }
+    }
+
+    fn unseeded_front(&self) -> Option<u64> {
+        (!self.unseeded.is_empty()).then(|| *self.unseeded.start())
+    }
+
+    fn unseeded_back(&self) -> Option<u64> {
+        (!self.unseeded.is_empty()).then(|| *self.unseeded.end())
+    }
 
-        let (_, &max_val) = self.data.iter().max_by_key(|(_k, &v)| v).unwrap();
-        let to_update: Vec<u64> = self.find_keys(max_val);
+    /// The product a row starts from: `k * k`, or if that overflows, the largest product of `k` with a smaller partner
+    /// which fits. `None` if no product of `k` with a factor from `min` on fits in a `u64`.
+    fn first_product(k: u64, min: u64) -> Option<u64> {
+        let partner = match k {
+            0 => 0,
+            _ => k.min(u64::MAX / k),
+        };
+        (partner >= min).then(|| k * partner)
+    }
+
+    /// Yield the remaining products in ascending blocks of `size`, for consumers which process products in batches.
+    /// Every block holds exactly `size` products except possibly the last.
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks(self, size: usize) -> ProductChunks {
+        assert!(size > 0, "chunk size must be non-zero");
+        ProductChunks { products: self, size }
+    }
 
-        for k in to_update {
-            let v = self.data.get_mut(&k).unwrap();
-            *v -= k;
+    /// Estimated bytes of memory which an iterator over `min..=max` holds at most: one row for each factor of the
+    /// range, which it reaches once it is consumed from both ends.
+    pub fn memory_estimate(min: u64, max: u64) -> usize {
+        // a hash map entry of two `u64`s plus the table's control byte and spare capacity
+        const ROW_BYTES: usize = 24;
+        let rows = (max as u128 + 1).saturating_sub(min as u128);
+        usize::try_from(rows).unwrap_or(usize::MAX).saturating_mul(ROW_BYTES)
+    }
+
+    /// Assert that the internal state is consistent, panicking with a description of the first violation.
+    ///
+    /// Every row must hold a product of its factor with a partner inside the range, and every pending product must lie
+    /// strictly between the values last returned from the front and the back. The latter guarantees that all further
+    /// items continue the ascending (or descending) order without repeating anything already returned. Intended for
+    /// fuzz targets and differential tests which interleave `next` and `next_back` arbitrarily.
+    pub fn debug_check_invariants(&self) {
+        if let (Some(lo), Some(hi)) = (self.last_min, self.last_max) {
+            assert!(lo < hi, "front fence {lo} is not below back fence {hi}");
         }
 
-        self.data.retain(|&k, &mut v| v >= k * self.min);
-        if let Some(lm) = self.last_min {
-            self.data.retain(|_, &mut v| v > lm);
+        for (&k, &v) in &self.data {
+            assert!((self.min..=self.max).contains(&k), "row {k} is outside {}..={}", self.min, self.max);
+            let partner = match k {
+                0 => (v == 0).then_some(self.min),
+                _ => v.is_multiple_of(k).then(|| v / k),
+            };
+            assert!(
+                partner.is_some_and(|j| (self.min..=self.max).contains(&j)),
+                "row {k} holds {v}, which is not a product of {k} with a factor in range"
+            );
+            if let Some(lo) = self.last_min {
+                assert!(v > lo, "row {k} holds {v}, which was already returned from the front");
+            }
+            if let Some(hi) = self.last_max {
+                assert!(v < hi, "row {k} holds {v}, which was already returned from the back");
+            }
         }
+    }
+}
+
+impl DoubleEndedIterator for SortedProducts {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.seed_back();
+        let max_val = self.data.values().copied().max()?;
+
+        // step every row holding the largest product down to its next partner, dropping rows which run out of
+        // partners or would repeat a product already returned from either end
+        let (min, last_min) = (self.min, self.last_min);
+        self.data.retain(|&k, v| {
+            if *v == max_val {
+                match v.checked_sub(k) {
+                    Some(next) => *v = next,
+                    None => return false,
+                }
+            }
+            // the row of zero has no partner to run out of, as every product of zero is zero
+            let partnered = v.checked_div(k).is_none_or(|partner| partner >= min);
+            *v < max_val && partnered && last_min.is_none_or(|lm| *v > lm)
+        });
 
         self.last_max = Some(max_val);
 
@@ -59,25 +250,25 @@ impl DoubleEndedIterator for ProductRange {
     }
 }
 
-impl Iterator for ProductRange {
+impl Iterator for SortedProducts {
     type Item = u64;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.data.is_empty() {
-            return None;
-        }
+        self.seed_front();
+        let min_val = self.data.values().copied().min()?;
 
-        let (_, &min_val) = self.data.iter().min_by_key(|(_k, &v)| v).unwrap();
-
-        let to_update: Vec<u64> = self.find_keys(min_val);
-        for k in to_update {
-            let v = self.data.get_mut(&k).unwrap();
-            *v += k;
-        }
-
-        self.data.retain(|&k, &mut v| v <= k * self.max);
-        if let Some(lm) = self.last_max {
-            self.data.retain(|_, &mut v| v < lm);
-        }
+        // step every row holding the smallest product up to its next partner, dropping rows which run out of
+        // partners, overflow, or would repeat a product already returned from either end
+        let (max, last_max) = (self.max, self.last_max);
+        self.data.retain(|&k, v| {
+            if *v == min_val {
+                match v.checked_add(k) {
+                    Some(next) => *v = next,
+                    None => return false,
+                }
+            }
+            let partnered = v.checked_div(k).is_none_or(|partner| partner <= max);
+            *v > min_val && partnered && last_max.is_none_or(|lm| *v < lm)
+        });
 
         self.last_min = Some(min_val);
 
@@ -85,36 +276,438 @@ impl Iterator for ProductRange {
     }
 }
 
+/// Iterator over blocks of ascending products, returned by [`SortedProducts::chunks`].
+#[derive(Debug, Clone)]
+pub struct ProductChunks {
+    products: SortedProducts,
+    size: usize,
+}
+
+impl Iterator for ProductChunks {
+    type Item = Vec<u64>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::with_capacity(self.size);
+        chunk.extend(self.products.by_ref().take(self.size));
+        (!chunk.is_empty()).then_some(chunk)
+    }
+}
+
 impl Palindrome {
     /// Create a `Palindrome` only if `value` is in fact a palindrome when represented in base ten. Otherwise, `None`.
+    /// Values within the table of [`warm_up`] are looked up in it.
     pub fn new(value: u64) -> Option<Palindrome> {
-        if value < 10 {
-            return Some(Palindrome(value));
-        }
-
-        let p1 = value.to_string();
-        let p2 = p1.chars().rev().collect::<String>();
+        let palindrome = warm::lookup(value).unwrap_or_else(|| value.is_palindrome());
+        palindrome.then_some(Palindrome(value))
+    }
 
-        match p1 == p2 {
+    /// Like [`Palindrome::new`], but usable in constant expressions, so that tables of palindromes are checked while
+    /// compiling: `const P: Option<Palindrome> = Palindrome::new_const(121);`.
+    pub const fn new_const(value: u64) -> Option<Palindrome> {
+        let (mut rest, mut reversed) = (value, 0u128);
+        while rest > 0 {
+            reversed = reversed * 10 + (rest % 10) as u128;
+            rest /= 10;
+        }
+        match reversed == value as u128 {
             true => Some(Palindrome(value)),
-            false => None
+            false => None,
         }
     }
 
+    /// Like [`Palindrome::new`], but using `buf` as scratch space for the digits of `value`. Reusing one buffer across
+    /// many candidates avoids repeating any setup per call.
+    pub fn check_with(buf: &mut DigitBuf, value: u64) -> Option<Palindrome> {
+        buf.is_palindrome(value).then_some(Palindrome(value))
+    }
+
+    /// Check that `value` is a palindrome in a custom [`DigitSystem`], such as balanced ternary, groups of digits or
+    /// zero-padded fields of a [`FixedWidth`].
+    /// The value need not be a base ten palindrome, so it is returned as a plain `u64`.
+    pub fn new_with<S: DigitSystem + ?Sized>(system: &S, value: u64) -> Option<u64> {
+        system.is_palindrome(value).then_some(value)
+    }
+
+    /// Create a `Palindrome` only if `value` is in fact a palindrome when represented in base ten. Otherwise, an
+    /// error describing the rejected value.
+    pub fn try_new(value: u64) -> Result<Palindrome, NotPalindrome> {
+        Palindrome::new(value).ok_or_else(|| NotPalindrome::new(value))
+    }
+
+    /// Every pair of factors `(a, b)` within `min..=max` whose product is this palindrome.
+    ///
+    /// The result is deterministic: each pair is normalized so that `a <= b`, no pair appears twice, and pairs are
+    /// sorted in ascending order of `a` (and therefore descending order of `b`).
+    pub fn factors(&self, min: u64, max: u64) -> Vec<(u64, u64)> {
+        self.factor_pairs(min, max).collect()
+    }
+
+    /// Whether this palindrome has at least one pair of factors within `min..=max`.
+    pub(crate) fn has_factors(&self, min: u64, max: u64) -> bool {
+        self.factor_pairs(min, max).next().is_some()
+    }
+
+    /// Lazily enumerate the pairs returned by [`Palindrome::factors`].
+    fn factor_pairs(&self, min: u64, max: u64) -> impl Iterator<Item = (u64, u64)> {
+        factor_pairs(self.0, min, max)
+    }
+
+    /// Whether this is a single digit, which is trivially a palindrome.
+    pub fn is_trivial(&self) -> bool {
+        self.0 < 10
+    }
+
     /// Get the value of this palindrome.
     pub fn into_inner(self) -> u64 {
         self.0
     }
+
+    /// Iterate in ascending order over the palindromes greater than or equal to `start`, constructing each one
+    /// from its leading digits rather than testing every integer.
+    pub fn iter_from(start: u64) -> Palindromes {
+        Palindromes(Source::Generated(RadixPalindromes::new(start, u64::MAX, 10)))
+    }
+
+    /// The digits of this palindrome in `base`, most significant first.
+    ///
+    /// Panics if `base` is not within `2..=256`.
+    pub fn to_digits_in_base(&self, base: u32) -> Vec<u8> {
+        self.digits_in_base(base).collect()
+    }
+
+    /// The digits of this palindrome in `base`, listed in `order`.
+    ///
+    /// Panics if `base` is not within `2..=256`.
+    pub fn to_digits_in_base_ordered(&self, base: u32, order: DigitOrder) -> Vec<u8> {
+        match order {
+            DigitOrder::MostSignificantFirst => self.digits_in_base(base).collect(),
+            DigitOrder::LeastSignificantFirst => self.digits_in_base(base).rev().collect(),
+        }
+    }
+
+    /// Iterate over the digits of this palindrome in `base`, most significant first, without allocating. The
+    /// iterator is double-ended, so `.rev()` gives the least significant first.
+    ///
+    /// Panics if `base` is not within `2..=256`.
+    pub fn digits_in_base(&self, base: u32) -> Digits {
+        Digits::new(self.0, base)
+    }
+
+    /// Format this palindrome with `separator` between groups of three digits, e.g. `906,609`.
+    pub fn to_grouped_string(&self, separator: char) -> String {
+        let digits = self.0.to_string();
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 * separator.len_utf8());
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// Iterate in ascending order over every palindrome representable in a `u64`, all 11,844,674,406 of them.
+    ///
+    /// Each palindrome is constructed from the previous one in constant space, so the iteration is lazy and
+    /// allocation-free. Like [`Palindrome::in_range`], it can also be consumed from the top with `.rev()`.
+    pub fn all() -> Palindromes {
+        Palindrome::in_range(0..=u64::MAX)
+    }
+
+    /// Iterate over the palindromes within `range`, constructing each one from its leading digits rather than
+    /// testing every integer. The iterator is double-ended, so `.rev()` walks down from the top of the range without
+    /// generating the lower palindromes first. Ranges covered by [`warm_up`] are read from its table instead.
+    pub fn in_range(range: RangeInclusive<u64>) -> Palindromes {
+        match warm::cached(&range) {
+            Some(cached) => Palindromes(Source::Cached(cached)),
+            None => Palindromes(Source::Generated(RadixPalindromes::new(*range.start(), *range.end(), 10))),
+        }
+    }
+
+    /// The `k` largest palindromes strictly below `n`, in descending order, generated downwards from `n` without
+    /// testing the values in between. Fewer than `k` if there are not that many.
+    pub fn k_largest_below(n: u64, k: usize) -> Vec<Palindrome> {
+        match n.checked_sub(1) {
+            Some(end) => Palindrome::in_range(0..=end).rev().take(k).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Assemble a palindrome from its decimal digits, most significant first, such as `[1, 2, 1]` for 121.
+    pub fn from_digits(digits: &[u8]) -> Result<Palindrome, FromDigitsError> {
+        Palindrome::from_digits_ordered(digits, DigitOrder::MostSignificantFirst)
+    }
+
+    /// Assemble a palindrome from its decimal digits listed in `order`. The order matters even though a palindrome
+    /// reads the same both ways, since it decides which digit must not be a leading zero and which value a rejected
+    /// slice reports.
+    pub fn from_digits_ordered(digits: &[u8], order: DigitOrder) -> Result<Palindrome, FromDigitsError> {
+        let (Some(&first), Some(&last)) = (digits.first(), digits.last()) else {
+            return Err(FromDigitsError::Empty);
+        };
+        if let Some((index, &digit)) = digits.iter().enumerate().find(|&(_, &digit)| digit >= 10) {
+            return Err(FromDigitsError::InvalidDigit { index, digit });
+        }
+        let leading = match order {
+            DigitOrder::MostSignificantFirst => first,
+            DigitOrder::LeastSignificantFirst => last,
+        };
+        if digits.len() > 1 && leading == 0 {
+            return Err(FromDigitsError::LeadingZero);
+        }
+
+        let append = |value: u64, &digit: &u8| value.checked_mul(10)?.checked_add(digit as u64);
+        let value = match order {
+            DigitOrder::MostSignificantFirst => digits.iter().try_fold(0, append),
+            DigitOrder::LeastSignificantFirst => digits.iter().rev().try_fold(0, append),
+        };
+        Ok(Palindrome::try_new(value.ok_or(FromDigitsError::Overflow)?)?)
+    }
+
+    /// Parse a palindrome written with the decimal digits of `alphabet`.
+    pub fn parse_with(s: &str, alphabet: DigitAlphabet) -> Result<Palindrome, ParseError> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut value: u64 = 0;
+        for c in s.chars() {
+            let digit = alphabet.digit(c).ok_or(ParseError::InvalidDigit(c))?;
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit as u64))
+                .ok_or(ParseError::Overflow)?;
+        }
+
+        Ok(Palindrome::try_new(value)?)
+    }
+
+    /// Parse a number written in `radix`, such as `"2331"` in hexadecimal, and check that it is a palindrome in base
+    /// ten. Digits above nine are the letters `a` to `z` in either case.
+    ///
+    /// Panics if `radix` is not within `2..=36`.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Palindrome, ParseError> {
+        Ok(Palindrome::try_new(parse_radix(s, radix)?)?)
+    }
+
+    /// Parse a number written in `radix` and check that its digits in that radix form a palindrome, as for `"1001"`
+    /// in binary. The value need not be a base ten palindrome, so it is returned as a plain `u64`.
+    ///
+    /// Panics if `radix` is not within `2..=36`.
+    pub fn value_from_str_radix(s: &str, radix: u32) -> Result<u64, ParseError> {
+        let value = parse_radix(s, radix)?;
+        match radix::reverse(value as u128, radix as u128) == value as u128 {
+            true => Ok(value),
+            false => Err(NotPalindrome::in_base(value, radix).into()),
+        }
+    }
+
+    /// Format this palindrome with the decimal digits of `alphabet`.
+    pub fn to_string_with(&self, alphabet: DigitAlphabet) -> String {
+        self.0
+            .to_string()
+            .bytes()
+            .filter_map(|b| alphabet.char(b - b'0'))
+            .collect()
+    }
+}
+
+/// Parse the digits of `s` in `radix` into a `u64`.
+fn parse_radix(s: &str, radix: u32) -> Result<u64, ParseError> {
+    assert!((2..=36).contains(&radix), "radix {radix} is not within 2..=36");
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut value: u64 = 0;
+    for c in s.chars() {
+        let digit = c.to_digit(radix).ok_or(ParseError::InvalidDigit(c))?;
+        value = value
+            .checked_mul(radix as u64)
+            .and_then(|v| v.checked_add(digit as u64))
+            .ok_or(ParseError::Overflow)?;
+    }
+    Ok(value)
+}
+
+/// Iterator over successive palindromes, returned by [`Palindrome::iter_from`] and [`Palindrome::in_range`].
+#[derive(Debug, Clone)]
+pub struct Palindromes(Source);
+
+/// Where a [`Palindromes`] iterator takes its values from.
+#[derive(Debug, Clone)]
+enum Source {
+    Generated(RadixPalindromes),
+    Cached(CachedPalindromes),
+}
+
+impl Iterator for Palindromes {
+    type Item = Palindrome;
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            Source::Generated(palindromes) => palindromes.next().map(Palindrome),
+            Source::Cached(palindromes) => palindromes.next().map(Palindrome),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Palindromes {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            Source::Generated(palindromes) => palindromes.next_back().map(Palindrome),
+            Source::Cached(palindromes) => palindromes.next_back().map(Palindrome),
+        }
+    }
+}
+
+impl From<Palindrome> for u64 {
+    fn from(p: Palindrome) -> u64 {
+        p.0
+    }
+}
+
+/// Implement `Sum` and `Product` of palindromes, by value and by reference, for an integer type. Like the
+/// implementations for the integers themselves, these panic on overflow in debug builds.
+macro_rules! impl_reductions {
+    ($($t:ty),*) => {$(
+        impl std::iter::Sum<Palindrome> for $t {
+            fn sum<I: Iterator<Item = Palindrome>>(iter: I) -> $t {
+                iter.map(|p| p.0 as $t).sum()
+            }
+        }
+
+        impl<'a> std::iter::Sum<&'a Palindrome> for $t {
+            fn sum<I: Iterator<Item = &'a Palindrome>>(iter: I) -> $t {
+                iter.map(|p| p.0 as $t).sum()
+            }
+        }
+
+        impl std::iter::Product<Palindrome> for $t {
+            fn product<I: Iterator<Item = Palindrome>>(iter: I) -> $t {
+                iter.map(|p| p.0 as $t).product()
+            }
+        }
+
+        impl<'a> std::iter::Product<&'a Palindrome> for $t {
+            fn product<I: Iterator<Item = &'a Palindrome>>(iter: I) -> $t {
+                iter.map(|p| p.0 as $t).product()
+            }
+        }
+    )*};
+}
+
+impl_reductions!(u64, u128);
+
+impl AsRef<u64> for Palindrome {
+    fn as_ref(&self) -> &u64 {
+        &self.0
+    }
 }
 
+/// `Palindrome` hashes and compares exactly like its value, so maps and sets keyed by palindromes can be queried
+/// with plain integers.
+impl Borrow<u64> for Palindrome {
+    fn borrow(&self) -> &u64 {
+        &self.0
+    }
+}
+
+impl PartialEq<u64> for Palindrome {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Palindrome> for u64 {
+    fn eq(&self, other: &Palindrome) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialOrd<u64> for Palindrome {
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<Palindrome> for u64 {
+    fn partial_cmp(&self, other: &Palindrome) -> Option<Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Palindrome {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=u64}", self.0)
+    }
+}
+
+impl fmt::Display for Palindrome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::LowerHex for Palindrome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperHex for Palindrome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Octal for Palindrome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Binary for Palindrome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
+/// Parses a palindrome in any supported [`DigitAlphabet`], detected from the first character. All digits must
+/// belong to that same alphabet.
+impl FromStr for Palindrome {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let first = s.chars().next().ok_or(ParseError::Empty)?;
+        let alphabet = DigitAlphabet::detect(first).ok_or(ParseError::InvalidDigit(first))?;
+        Palindrome::parse_with(s, alphabet)
+    }
+}
+
+/// Find the smallest and largest palindromic products of two factors within `min..=max`, with the strategy
+/// [`Strategy::for_range`] picks for the range.
 pub fn palindrome_products(min: u64, max: u64) -> Option<(Palindrome, Palindrome)> {
-    // 
-    let min_pal = ProductRange::new(min, max)
-        .find_map(|i| Palindrome::new(i));
+    palindrome_products_with(min, max, Strategy::for_range(min, max))
+}
+
+/// The product-first search, on 32-bit factors whenever they fit.
+pub(crate) fn product_first(min: u64, max: u64) -> Option<(Palindrome, Palindrome)> {
+    match (u32::try_from(min), u32::try_from(max)) {
+        (Ok(min), Ok(max)) => palindrome_products_u32(min, max),
+        _ => extreme_products(min, max, Palindrome::new),
+    }
+}
+
+/// Find the smallest and largest products of two factors within `min..=max` accepted by `check`.
+///
+/// This is the base-agnostic core of the product searches: `check` decides what counts as a palindrome.
+pub(crate) fn extreme_products<T>(min: u64, max: u64, check: impl Fn(u64) -> Option<T>) -> Option<(T, T)> {
+    let min_pal = SortedProducts::new(min, max)
+        .find_map(&check);
 
-    let max_pal = ProductRange::new(min, max)
+    let max_pal = SortedProducts::new(min, max)
         .rev()
-        .find_map(|i| Palindrome::new(i));
+        .find_map(&check);
 
     match (min_pal, max_pal) {
         (Some(min), Some(max)) => Some((min, max)),
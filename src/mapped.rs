@@ -0,0 +1,148 @@
+//! Palindrome tables read in place from a memory-mapped file, built with the `mmap` feature.
+
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::ops::{Range, RangeBounds};
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use crate::set::{self, BUCKETS};
+use crate::table::{invalid_data, parse_header, HEADER_LEN};
+use crate::{Palindrome, PalindromeSet};
+
+/// A read-only set of palindromes looked up directly in a table file written by
+/// [`PalindromeSet::save`](crate::PalindromeSet::save), without copying its values into the heap.
+///
+/// The file is mapped into memory rather than read, so the operating system loads its pages on demand and every
+/// process mapping the same file shares them: workers of a service can each open a table of hundreds of megabytes
+/// while it is stored in memory only once. Lookups cost the same as in a [`PalindromeSet`], reading the stored halves
+/// straight from the mapping.
+pub struct MappedPalindromeSet {
+    map: Mmap,
+    /// The index of the first value of each bucket among all values, followed by the total number of values.
+    starts: Vec<usize>,
+}
+
+impl MappedPalindromeSet {
+    /// Map the table file at `path`. Like [`PalindromeSet::load`], this checks the whole table and reports malformed
+    /// files as [`io::ErrorKind::InvalidData`], which reads every page once.
+    ///
+    /// The file must not be modified or truncated while mapped: the set would see the new contents, or the process
+    /// could be killed for reading past the end of a truncated file. Write new tables to a new file and rename it
+    /// over the old one instead, which leaves existing mappings untouched.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<MappedPalindromeSet> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is only ever read, and the documentation above forbids changing the file meanwhile
+        let map = unsafe { Mmap::map(&file)? };
+        let mut starts: Vec<usize> = Vec::with_capacity(BUCKETS + 1);
+        starts.push(0);
+        for len in parse_header(&map)? {
+            let start = starts.last().copied().unwrap_or_default();
+            let end = usize::try_from(len).ok().and_then(|len| start.checked_add(len));
+            starts.push(end.ok_or_else(|| invalid_data("palindrome table values are malformed"))?);
+        }
+
+        let values_len = starts.last().and_then(|&total| total.checked_mul(4));
+        match values_len.and_then(|len| len.checked_add(HEADER_LEN)) {
+            Some(len) if map.len() < len => {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "palindrome table is truncated"))
+            }
+            Some(len) if map.len() > len => return Err(invalid_data("trailing bytes after palindrome table")),
+            Some(_) => {}
+            None => return Err(invalid_data("palindrome table values are malformed")),
+        }
+
+        let mapped = MappedPalindromeSet { map, starts };
+        match (0..BUCKETS).all(|bucket| set::is_valid_bucket(bucket, mapped.bucket(bucket).map(|i| mapped.rest(i)))) {
+            true => Ok(mapped),
+            false => Err(invalid_data("palindrome table values are malformed")),
+        }
+    }
+
+    /// Number of palindromes in the set.
+    pub fn len(&self) -> usize {
+        self.starts[BUCKETS]
+    }
+
+    /// Whether the set contains no palindromes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether `p` is in the set.
+    pub fn contains(&self, p: Palindrome) -> bool {
+        let (bucket, rest) = set::key(p);
+        let i = self.partition_point(bucket, |r| r < rest);
+        i < self.starts[bucket + 1] && self.rest(i) == rest
+    }
+
+    /// Iterate over the palindromes in ascending order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Palindrome> + '_ {
+        self.iter_range(..)
+    }
+
+    /// Iterate in ascending order over the palindromes whose value lies within `range`.
+    pub fn iter_range(&self, range: impl RangeBounds<u64>) -> impl DoubleEndedIterator<Item = Palindrome> + '_ {
+        let ((start, end), buckets) = match self.positions(range) {
+            Some((start, end, buckets)) => ((start, end), buckets),
+            None => ((0, 0), 0..0),
+        };
+        buckets.flat_map(move |bucket| {
+            let values = self.bucket(bucket);
+            (values.start.max(start)..values.end.min(end)).map(move |i| set::value(bucket, self.rest(i)))
+        })
+    }
+
+    /// Number of palindromes in the set within `lo..=hi`.
+    pub fn count_between(&self, lo: u64, hi: u64) -> usize {
+        self.positions(lo..=hi).map_or(0, |(start, end, _)| end - start)
+    }
+
+    /// Copy the whole table into an ordinary [`PalindromeSet`], which can be modified.
+    pub fn to_set(&self) -> PalindromeSet {
+        self.iter().collect()
+    }
+
+    /// Translate a range of values into the index of the first member at or after its start, one past the index of
+    /// the last member at or before its end, and the buckets in between. `None` if no palindrome lies within the
+    /// range.
+    fn positions(&self, range: impl RangeBounds<u64>) -> Option<(usize, usize, Range<usize>)> {
+        let ((start_bucket, start_rest), (end_bucket, end_rest)) = set::keys_within(range)?;
+        let start = self.partition_point(start_bucket, |r| r < start_rest);
+        let end = self.partition_point(end_bucket, |r| r <= end_rest);
+        Some((start, end.max(start), start_bucket..end_bucket + 1))
+    }
+
+    /// The indices of the values in `bucket`.
+    fn bucket(&self, bucket: usize) -> Range<usize> {
+        self.starts[bucket]..self.starts[bucket + 1]
+    }
+
+    /// The stored half of the value at index `i`.
+    fn rest(&self, i: usize) -> u32 {
+        let at = HEADER_LEN + 4 * i;
+        u32::from_le_bytes(self.map[at..at + 4].try_into().unwrap_or_default())
+    }
+
+    /// The index of the first value in `bucket` for which `pred` fails, as in [`slice::partition_point`].
+    fn partition_point(&self, bucket: usize, pred: impl Fn(u32) -> bool) -> usize {
+        let Range { start: mut lo, end: mut hi } = self.bucket(bucket);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match pred(self.rest(mid)) {
+                true => lo = mid + 1,
+                false => hi = mid,
+            }
+        }
+        lo
+    }
+}
+
+impl fmt::Debug for MappedPalindromeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the table may be far too long to show
+        f.debug_struct("MappedPalindromeSet").field("len", &self.len()).finish()
+    }
+}
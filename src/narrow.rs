@@ -0,0 +1,94 @@
+//! The palindrome product search specialized to 32-bit factors.
+//!
+//! Every product of two `u32`s fits in a `u64`, so no product needs an overflow check, and each row of the frontier
+//! only stores the 32-bit partner of its factor. Rows are kept in a `Vec` indexed by factor and become active only
+//! once the products ahead of them are reached, so each step scans the active rows rather than the whole range.
+
+use crate::Palindrome;
+
+/// Marks a row whose products are exhausted. A real partner is never zero except in the row of factor zero, and its
+/// first product ends the search because zero is a palindrome.
+const DONE: u32 = 0;
+
+/// The smallest and largest palindromic products of two factors within `min..=max`, like
+/// [`palindrome_products`](crate::palindrome_products), which delegates here whenever the factors fit in a `u32`.
+pub fn palindrome_products_u32(min: u32, max: u32) -> Option<(Palindrome, Palindrome)> {
+    if min > max {
+        return None;
+    }
+    Some((smallest(min, max)?, largest(min, max)?))
+}
+
+/// Walk the products upwards. The row of factor `min + i` holds its next partner `b >= a`, or `DONE`; row `i` is
+/// activated once the smallest pending product reaches its square.
+fn smallest(min: u32, max: u32) -> Option<Palindrome> {
+    let mut partners: Vec<u32> = Vec::new();
+    let product = |i: usize, b: u32| (min as u64 + i as u64) * b as u64;
+
+    loop {
+        // the next row starts at its square, which may undercut every active row
+        let next = min as u64 + partners.len() as u64;
+        let next_square = (next <= max as u64).then(|| next * next);
+        let active = partners
+            .iter()
+            .enumerate()
+            .filter(|&(_, &b)| b != DONE)
+            .map(|(i, &b)| product(i, b))
+            .min();
+        let current = match (active, next_square) {
+            (None, None) => return None,
+            (Some(p), None) => p,
+            (None, Some(square)) => square,
+            (Some(p), Some(square)) => p.min(square),
+        };
+        if next_square == Some(current) {
+            partners.push(next as u32);
+        }
+
+        if let Some(p) = Palindrome::new(current) {
+            return Some(p);
+        }
+        for (i, b) in partners.iter_mut().enumerate() {
+            if *b != DONE && product(i, *b) == current {
+                *b = if *b == max { DONE } else { *b + 1 };
+            }
+        }
+    }
+}
+
+/// Walk the products downwards. The row of factor `max - i` holds its next partner `b >= a`, or `DONE`; row `i` is
+/// activated once the largest pending product falls to its product with `max`.
+fn largest(min: u32, max: u32) -> Option<Palindrome> {
+    let mut partners: Vec<u32> = Vec::new();
+    let product = |i: usize, b: u32| (max as u64 - i as u64) * b as u64;
+
+    loop {
+        let next = (max as u64).checked_sub(partners.len() as u64).filter(|&a| a >= min as u64);
+        let next_first = next.map(|a| a * max as u64);
+        let active = partners
+            .iter()
+            .enumerate()
+            .filter(|&(_, &b)| b != DONE)
+            .map(|(i, &b)| product(i, b))
+            .max();
+        let current = match (active, next_first) {
+            (None, None) => return None,
+            (Some(p), None) => p,
+            (None, Some(first)) => first,
+            (Some(p), Some(first)) => p.max(first),
+        };
+        if next_first == Some(current) {
+            partners.push(max);
+        }
+
+        if let Some(p) = Palindrome::new(current) {
+            return Some(p);
+        }
+        for (i, b) in partners.iter_mut().enumerate() {
+            let a = max - i as u32;
+            if *b != DONE && product(i, *b) == current {
+                *b = if *b == a { DONE } else { *b - 1 };
+            }
+        }
+    }
+}
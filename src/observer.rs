@@ -0,0 +1,62 @@
+//! Hooks into the traversal of a palindrome product search.
+
+use std::fmt;
+use std::ops::ControlFlow;
+
+use crate::Palindrome;
+
+/// Why the search skipped a product without testing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PruneReason {
+    /// The product cannot improve on the best palindrome found so far, and neither can the rest of its row.
+    Bound,
+    /// The product does not fit in a `u64`; it is reported as `u64::MAX`.
+    Overflow,
+    /// The palindrome has no factor pair which the search accepts.
+    NoFactors,
+}
+
+impl fmt::Display for PruneReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PruneReason::Bound => "bound",
+            PruneReason::Overflow => "overflow",
+            PruneReason::NoFactors => "no-factors",
+        })
+    }
+}
+
+/// Receives the events of a search run with [`Search::run_observed`](crate::Search::run_observed).
+///
+/// Every hook does nothing by default, so observers only implement what they need. Returning
+/// [`ControlFlow::Break`] from [`on_candidate`](SearchObserver::on_candidate) or [`on_tick`](SearchObserver::on_tick)
+/// stops the search, which then fails with [`SearchError::Stopped`](crate::SearchError::Stopped).
+pub trait SearchObserver {
+    /// The search is about to test `product`: a product of two factors for the product-first strategy, or a
+    /// palindrome for the palindrome-first strategy.
+    fn on_candidate(&mut self, product: u64) -> ControlFlow<()> {
+        let _ = product;
+        ControlFlow::Continue(())
+    }
+
+    /// The search is still busy with its current candidate, as while trying the factors of a large palindrome, and
+    /// checks in about every 65,536 steps. Returning [`ControlFlow::Break`] stops the search just as from
+    /// [`on_candidate`](SearchObserver::on_candidate), so that deadlines are kept even between candidates.
+    fn on_tick(&mut self) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// The search skipped `product` for `reason`.
+    fn on_pruned(&mut self, product: u64, reason: PruneReason) {
+        let _ = (product, reason);
+    }
+
+    /// The search found `p`, produced by `factors`, which improves on every palindrome it found before in the same
+    /// direction. The final smallest and largest palindromes are the last ones reported from each direction.
+    fn on_palindrome_found(&mut self, p: Palindrome, factors: &[(u64, u64)]) {
+        let _ = (p, factors);
+    }
+}
+
+/// The observer which ignores every event.
+impl SearchObserver for () {}
@@ -0,0 +1,86 @@
+//! A trait for values which can be read backwards.
+
+use crate::Palindrome;
+
+/// Values which read the same in both directions can be palindromes: integers by their decimal digits and strings by
+/// their `char`s.
+///
+/// ```
+/// use palindrome_products::Palindromic;
+///
+/// assert!(9009u64.is_palindrome());
+/// assert_eq!(123u16.reversed(), Some(321));
+/// assert!("racecar".is_palindrome());
+/// assert_eq!("abc".reversed(), "cba");
+/// ```
+pub trait Palindromic {
+    /// The type of the reversed value. Reversing an integer can overflow it, so integers reverse into an `Option`;
+    /// strings reverse into an owned `String`.
+    type Reversed;
+
+    /// Whether this value reads the same in both directions.
+    fn is_palindrome(&self) -> bool;
+
+    /// This value read backwards.
+    fn reversed(&self) -> Self::Reversed;
+}
+
+macro_rules! impl_palindromic {
+    ($($t:ty),*) => {$(
+        impl Palindromic for $t {
+            type Reversed = Option<$t>;
+
+            fn is_palindrome(&self) -> bool {
+                // a palindrome is its own reversal, so a reversal which overflows is never one
+                self.reversed() == Some(*self)
+            }
+
+            fn reversed(&self) -> Option<$t> {
+                let (mut n, mut reversed): ($t, $t) = (*self, 0);
+                while n > 0 {
+                    reversed = reversed.checked_mul(10)?.checked_add(n % 10)?;
+                    n /= 10;
+                }
+                Some(reversed)
+            }
+        }
+    )*};
+}
+
+impl_palindromic!(u8, u16, u32, u64, u128, usize);
+
+impl Palindromic for str {
+    type Reversed = String;
+
+    fn is_palindrome(&self) -> bool {
+        self.chars().eq(self.chars().rev())
+    }
+
+    fn reversed(&self) -> String {
+        self.chars().rev().collect()
+    }
+}
+
+impl Palindromic for Palindrome {
+    type Reversed = Palindrome;
+
+    fn is_palindrome(&self) -> bool {
+        true
+    }
+
+    fn reversed(&self) -> Palindrome {
+        *self
+    }
+}
+
+impl<T: Palindromic + ?Sized> Palindromic for &T {
+    type Reversed = T::Reversed;
+
+    fn is_palindrome(&self) -> bool {
+        (**self).is_palindrome()
+    }
+
+    fn reversed(&self) -> T::Reversed {
+        (**self).reversed()
+    }
+}
@@ -0,0 +1,169 @@
+//! Multi-threaded searches built on rayon.
+
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rayon::prelude::*;
+
+use crate::radix::{half_blocks, mirror};
+use crate::{Palindrome, Strategy};
+
+/// Find the smallest and largest palindromic products of two factors within `min..=max`, spreading the work over
+/// rayon's thread pool.
+///
+/// The result is always identical to [`palindrome_products`](crate::palindrome_products). Each task handles the
+/// products whose larger factor is one particular `b`, and the best answers found so far are shared between tasks so
+/// that rows which cannot improve on them stop early. The smallest products are searched first, in rows of ascending
+/// `b`, and then the largest, in rows of descending `b`.
+pub fn par_palindrome_products(min: u64, max: u64) -> Option<(Palindrome, Palindrome)> {
+    // `u64::MAX` is not a palindrome and zero only counts when a factor is zero, so both work as "nothing yet"
+    let smallest = AtomicU64::new(u64::MAX);
+    let largest = AtomicU64::new(0);
+
+    (min..=max).into_par_iter().for_each(|b| {
+        let smallest_in_row = (min..=b)
+            .map_while(|a| a.checked_mul(b).filter(|&product| product < smallest.load(Ordering::Relaxed)))
+            .find(|&product| Palindrome::new(product).is_some());
+        if let Some(product) = smallest_in_row {
+            smallest.fetch_min(product, Ordering::Relaxed);
+        }
+    });
+
+    // the largest products lie in the rows of the largest `b`, so those go first: once the best answer is found,
+    // every later row whose square cannot beat it is skipped outright
+    (min..=max).into_par_iter().map(|i| max - (i - min)).for_each(|b| {
+        if b.saturating_mul(b) <= largest.load(Ordering::Relaxed) {
+            return;
+        }
+        let largest_in_row = (min..=b)
+            .rev()
+            .filter_map(|a| a.checked_mul(b))
+            .take_while(|&product| product > largest.load(Ordering::Relaxed))
+            .find(|&product| Palindrome::new(product).is_some());
+        if let Some(product) = largest_in_row {
+            largest.fetch_max(product, Ordering::Relaxed);
+        }
+    });
+
+    match smallest.into_inner() {
+        u64::MAX => None,
+        smallest => Some((Palindrome(smallest), Palindrome(largest.into_inner()))),
+    }
+}
+
+/// [`palindrome_products_with`](crate::palindrome_products_with) on rayon's thread pool: [`par_palindrome_products`]
+/// for [`Strategy::ProductFirst`], and for [`Strategy::PalindromeFirst`] the same walk from each end of the palindromes
+/// between `min * min` and `max * max`, testing them for a factor pair in batches spread over the pool.
+///
+/// The result is always identical to the sequential search. Batches start with one palindrome per thread and double
+/// up to 4096, so that an early hit wastes little work while long runs of palindromes without factors keep every
+/// thread busy.
+pub fn par_palindrome_products_with(min: u64, max: u64, strategy: Strategy) -> Option<(Palindrome, Palindrome)> {
+    match strategy {
+        Strategy::ProductFirst => par_palindrome_products(min, max),
+        Strategy::PalindromeFirst => par_palindrome_first(min, max),
+    }
+}
+
+/// Largest number of palindromes tested together by [`first_with_factors`].
+const MAX_BATCH: usize = 1 << 12;
+
+fn par_palindrome_first(min: u64, max: u64) -> Option<(Palindrome, Palindrome)> {
+    if min > max {
+        return None;
+    }
+
+    let (lower, upper) = (min.saturating_mul(min), max.saturating_mul(max));
+    let smallest = first_with_factors(Palindrome::in_range(lower..=upper), min, max)?;
+    let largest = first_with_factors(Palindrome::in_range(smallest.into_inner()..=upper).rev(), min, max)?;
+    Some((smallest, largest))
+}
+
+/// The first of `palindromes` with a factor pair within `min..=max`.
+fn first_with_factors(mut palindromes: impl Iterator<Item = Palindrome>, min: u64, max: u64) -> Option<Palindrome> {
+    let mut batch_len = rayon::current_num_threads();
+    loop {
+        let batch: Vec<Palindrome> = palindromes.by_ref().take(batch_len).collect();
+        if batch.is_empty() {
+            return None;
+        }
+        if let Some(&p) = batch.par_iter().find_first(|p| p.has_factors(min, max)) {
+            return Some(p);
+        }
+        batch_len = (batch_len * 2).min(MAX_BATCH);
+    }
+}
+
+/// Number of palindromes within `range`, counted in parallel by blocks of equal digit count.
+///
+/// Within a block, palindromes correspond one-to-one with their leading halves, so each block is counted from the
+/// first and last half without generating anything in between.
+pub fn count_palindromes_in(range: RangeInclusive<u64>) -> u64 {
+    half_blocks(*range.start(), *range.end(), 10)
+        .into_par_iter()
+        .map(|(_, first, last)| (last - first + 1) as u64)
+        .sum()
+}
+
+/// [`count_palindrome_products`](crate::count_palindrome_products) in parallel: the palindromes between `min * min`
+/// and `max * max` are split by digit count and leading half, and checked for a factor pair on every thread.
+pub fn count_palindrome_products_parallel(min: u64, max: u64) -> usize {
+    half_blocks(min.saturating_mul(min), max.saturating_mul(max), 10)
+        .into_par_iter()
+        .flat_map(|(len, first, last)| (first as u64..=last as u64).into_par_iter().map(move |half| (len, half)))
+        .filter(|&(len, half)| Palindrome(mirror(half as u128, len, 10) as u64).has_factors(min, max))
+        .count()
+}
+
+/// Number of distinct products of two factors within `min..=max` which fit in a `u64`: as many as a
+/// [`SortedProducts`](crate::SortedProducts) yields, counted in parallel without walking them in order.
+///
+/// The values between `min * min` and `max * max` are split into windows of about a million products each, which
+/// are marked in a bitmap of the window where they lie densely, or else sorted and deduplicated. Memory therefore
+/// stays within some megabytes per thread, but the time grows with the number of factor pairs.
+pub fn count_distinct_products(min: u64, max: u64) -> u64 {
+    if min > max {
+        return 0;
+    }
+
+    let (first, last) = (min.saturating_mul(min), max.saturating_mul(max));
+    let factors = (max - min).saturating_add(1);
+    let pairs = (factors as u128 * (factors as u128 + 1) / 2).try_into().unwrap_or(u64::MAX);
+    // windows narrower than a row of factors would mostly miss each row, so that visiting the rows dominates
+    let width = ((last - first) / (pairs >> 20).max(1)).max(factors);
+    (0..=(last - first) / width)
+        .into_par_iter()
+        .map(|i| {
+            let lo = first + i * width;
+            let hi = lo.saturating_add(width - 1).min(last);
+            let products = window_products(min, max, lo, hi);
+            // a bitmap of up to a million words takes no longer to count than the products take to sort
+            match hi - lo < 1 << 26 {
+                true => {
+                    let mut marked = vec![0u64; ((hi - lo) / 64 + 1) as usize];
+                    for offset in products.map(|product| product - lo) {
+                        marked[(offset / 64) as usize] |= 1 << (offset % 64);
+                    }
+                    marked.iter().map(|word| word.count_ones() as u64).sum()
+                }
+                false => {
+                    let mut products: Vec<u64> = products.collect();
+                    products.sort_unstable();
+                    products.dedup();
+                    products.len() as u64
+                }
+            }
+        })
+        .sum()
+}
+
+/// The products of two factors within `min..=max` which lie within `lo..=hi`, each once for every factor pair.
+fn window_products(min: u64, max: u64, lo: u64, hi: u64) -> impl Iterator<Item = u64> {
+    // zero is the product of zero with anything
+    let zero = (lo == 0 && min == 0).then_some(0);
+    // `a` is the smaller factor, so its square is at most `hi`, and its partner at most `max`
+    let rows = (min.max(1).max(lo.div_ceil(max.max(1)))..=max)
+        .take_while(move |&a| a.checked_mul(a).is_some_and(|square| square <= hi))
+        .flat_map(move |a| (a.max(lo.div_ceil(a))..=max.min(hi / a)).map(move |b| a * b));
+    zero.into_iter().chain(rows)
+}
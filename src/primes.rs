@@ -0,0 +1,135 @@
+//! Primality and prime factorization of palindromes.
+
+use crate::Palindrome;
+
+/// Witnesses which make Miller-Rabin deterministic for every `u64`.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Whether `n` is prime, by a deterministic Miller-Rabin test.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    // n - 1 = d * 2^s with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    WITNESSES.iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// A non-trivial divisor of the odd composite `n`, by Pollard's rho with Floyd cycle detection. Each failed walk
+/// retries with the next polynomial `x^2 + c`.
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let step = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+        let (mut slow, mut fast) = (2, 2);
+        let divisor = loop {
+            slow = step(slow);
+            fast = step(step(fast));
+            let d = gcd(slow.abs_diff(fast), n);
+            if d != 1 {
+                break d;
+            }
+        };
+        if divisor != n {
+            return divisor;
+        }
+    }
+    unreachable!("some polynomial splits every odd composite")
+}
+
+/// Collect the prime factors of `n`, with repetition and in no particular order.
+fn prime_factors(n: u64, factors: &mut Vec<u64>) {
+    if n == 1 {
+        return;
+    }
+    if is_prime(n) {
+        factors.push(n);
+        return;
+    }
+    let divisor = pollard_rho(n);
+    prime_factors(divisor, factors);
+    prime_factors(n / divisor, factors);
+}
+
+/// The prime factorization of `n` as `(prime, exponent)` pairs in ascending order of the prime. Zero and one have no
+/// prime factors and give an empty factorization.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // small primes first, which also leaves Pollard's rho with an odd input
+    let mut factors = Vec::new();
+    for p in WITNESSES {
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+    }
+    prime_factors(n, &mut factors);
+    factors.sort_unstable();
+
+    let mut factorization: Vec<(u64, u32)> = Vec::new();
+    for p in factors {
+        match factorization.last_mut() {
+            Some((last, exponent)) if *last == p => *exponent += 1,
+            _ => factorization.push((p, 1)),
+        }
+    }
+    factorization
+}
+
+impl Palindrome {
+    /// Whether this palindrome is prime.
+    pub fn is_prime(&self) -> bool {
+        is_prime(self.into_inner())
+    }
+
+    /// The prime factorization of this palindrome as `(prime, exponent)` pairs in ascending order of the prime. See
+    /// [`factorize`].
+    pub fn factorize(&self) -> Vec<(u64, u32)> {
+        factorize(self.into_inner())
+    }
+}
@@ -0,0 +1,294 @@
+//! Retained results of a palindrome product search.
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use crate::{palindrome_products, Palindrome, SortedProducts};
+
+/// The smallest and largest palindromic products of two factors within `min..=max`.
+///
+/// Unlike the tuple returned by [`palindrome_products`], this keeps the range alongside the answer so the search can
+/// be resumed with [`extend_max`] when the range grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PalindromeProducts {
+    min: u64,
+    max: u64,
+    smallest: Option<Palindrome>,
+    largest: Option<Palindrome>,
+}
+
+impl PalindromeProducts {
+    /// Search the factors within `min..=max`.
+    pub fn new(min: u64, max: u64) -> PalindromeProducts {
+        let (smallest, largest) = palindrome_products(min, max).unzip();
+        PalindromeProducts { min, max, smallest, largest }
+    }
+
+    /// Search the factors with exactly `digits` decimal digits, as in the Project Euler formulation of the problem.
+    /// `None` if no `u64` has that many digits.
+    pub fn with_digits(digits: u32) -> Option<PalindromeProducts> {
+        let factors = digit_range(digits)?;
+        Some(PalindromeProducts::new(*factors.start(), *factors.end()))
+    }
+
+    /// Assemble a result whose extremes were computed elsewhere.
+    pub(crate) fn from_parts(
+        min: u64,
+        max: u64,
+        smallest: Option<Palindrome>,
+        largest: Option<Palindrome>,
+    ) -> PalindromeProducts {
+        PalindromeProducts { min, max, smallest, largest }
+    }
+
+    /// The smallest factor of the searched range.
+    pub fn min(&self) -> u64 {
+        self.min
+    }
+
+    /// The largest factor of the searched range.
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+
+    /// The smallest palindromic product, if any.
+    pub fn smallest(&self) -> Option<Palindrome> {
+        self.smallest
+    }
+
+    /// The largest palindromic product, if any.
+    pub fn largest(&self) -> Option<Palindrome> {
+        self.largest
+    }
+
+    /// Number of factors in the range.
+    pub fn factor_count(&self) -> u64 {
+        (self.max as u128 + 1).saturating_sub(self.min as u128).try_into().unwrap_or(u64::MAX)
+    }
+
+    /// Number of factor pairs `(a, b)` with `a <= b` in the range, saturating at `u64::MAX`.
+    pub fn factor_pair_count(&self) -> u64 {
+        let factors = self.factor_count() as u128;
+        (factors * (factors + 1) / 2).try_into().unwrap_or(u64::MAX)
+    }
+
+    /// Assert that the retained answer is consistent with the range, panicking with a description of the first
+    /// violation: both extremes are present or both absent, they are ordered, and each has a factor pair within
+    /// the range.
+    pub fn debug_check_invariants(&self) {
+        assert_eq!(
+            self.smallest.is_some(),
+            self.largest.is_some(),
+            "only one of smallest {:?} and largest {:?} is present",
+            self.smallest,
+            self.largest
+        );
+        if let (Some(smallest), Some(largest)) = (self.smallest, self.largest) {
+            assert!(smallest <= largest, "smallest {smallest} exceeds largest {largest}");
+        }
+        for p in self.smallest.iter().chain(&self.largest) {
+            assert!(
+                !p.factors(self.min, self.max).is_empty(),
+                "{p} has no factors within {}..={}",
+                self.min,
+                self.max
+            );
+        }
+    }
+}
+
+/// The positive factors with exactly `digits` decimal digits, such as `100..=999` for three digits. `None` for zero
+/// digits or more than a `u64` can hold.
+pub fn digit_range(digits: u32) -> Option<RangeInclusive<u64>> {
+    let start = match digits {
+        0 => return None,
+        _ => 10u64.checked_pow(digits - 1)?,
+    };
+    let end = 10u64.checked_pow(digits).map_or(u64::MAX, |limit| limit - 1);
+    Some(start..=end)
+}
+
+/// Grow the factor range of a previous search to `min..=new_max`, reusing its answer.
+///
+/// Only products with a factor above the old maximum are new, and the previous extremes bound how far those need to
+/// be explored: the search for a smaller palindrome stops at the current smallest and the search for a larger one
+/// stops at the current largest. Shrinking the range cannot reuse anything and falls back to a fresh search.
+pub fn extend_max(state: &mut PalindromeProducts, new_max: u64) {
+    if new_max < state.max {
+        *state = PalindromeProducts::new(state.min, new_max);
+        return;
+    }
+
+    let Some(from) = state.max.checked_add(1).map(|m| m.max(state.min)) else {
+        return;
+    };
+    if from <= new_max {
+        state.smallest = smallest_with_new_factor(state.min, from, new_max, state.smallest);
+        state.largest = largest_with_new_factor(state.min, from, new_max, state.largest);
+    }
+    state.max = new_max;
+}
+
+/// The smallest palindrome `a * b` with `min <= a <= b`, `from <= b <= max`, or `best` if none is smaller.
+pub(crate) fn smallest_with_new_factor(min: u64, from: u64, max: u64, mut best: Option<Palindrome>) -> Option<Palindrome> {
+    for a in min..=max {
+        let first = a.max(from);
+        match a.checked_mul(first) {
+            Some(product) if best.is_none_or(|p| product < p) => {}
+            _ => break,
+        }
+
+        for b in first..=max {
+            match a.checked_mul(b) {
+                Some(product) if best.is_none_or(|p| product < p) => {
+                    if let Some(p) = Palindrome::new(product) {
+                        best = Some(p);
+                        break;
+                    }
+                }
+                _ => break,
+            }
+        }
+    }
+    best
+}
+
+/// The largest palindrome `a * b` with `min <= a <= b`, `from <= b <= max`, or `best` if none is larger.
+pub(crate) fn largest_with_new_factor(min: u64, from: u64, max: u64, mut best: Option<Palindrome>) -> Option<Palindrome> {
+    for b in (from..=max).rev() {
+        if b.checked_mul(b).is_some_and(|square| best.is_some_and(|p| square <= p)) {
+            break;
+        }
+
+        for a in (min..=b).rev() {
+            let Some(product) = a.checked_mul(b) else {
+                continue;
+            };
+            if best.is_some_and(|p| product <= p) {
+                break;
+            }
+            if let Some(p) = Palindrome::new(product) {
+                best = Some(p);
+                break;
+            }
+        }
+    }
+    best
+}
+
+/// Every palindromic product of two factors within `min..=max`, mapped to all of its factor pairs.
+///
+/// The whole range is traversed once, so this is much cheaper than calling [`Palindrome::factors`] for each
+/// palindrome. Factor pairs follow the same guarantee: normalized so that `a <= b`, without duplicates, and sorted in
+/// ascending order of `a`.
+pub fn palindrome_product_map(min: u64, max: u64) -> BTreeMap<Palindrome, Vec<(u64, u64)>> {
+    let mut map: BTreeMap<Palindrome, Vec<(u64, u64)>> = BTreeMap::new();
+    for a in min..=max {
+        for b in a..=max {
+            let Some(product) = a.checked_mul(b) else {
+                break;
+            };
+            if let Some(p) = Palindrome::new(product) {
+                map.entry(p).or_default().push((a, b));
+            }
+        }
+    }
+    map
+}
+
+/// The `k`-th smallest distinct palindromic product of two factors within `min..=max`, counting from zero like
+/// [`Iterator::nth`].
+///
+/// The ordered traversal of [`SortedProducts`] is advanced only until the `k`-th palindrome is reached, so nothing
+/// beyond it is enumerated or stored.
+pub fn nth_palindrome_product(min: u64, max: u64, k: usize) -> Option<Palindrome> {
+    SortedProducts::new(min, max).filter_map(Palindrome::new).nth(k)
+}
+
+/// Number of distinct palindromic products of two factors within `min..=max`.
+///
+/// Rather than enumerating products, this walks the palindromes between `min * min` and `max * max` and checks each
+/// for a factor pair in the range, which needs constant memory.
+pub fn count_palindrome_products(min: u64, max: u64) -> usize {
+    distinct_palindrome_products(min, max).count()
+}
+
+/// Sum of the distinct palindromic products of two factors within `min..=max`, walking the palindromes as
+/// [`count_palindrome_products`] does. There are too few palindromes in a `u64` for the sum to overflow a `u128`.
+pub fn sum_of_palindrome_products(min: u64, max: u64) -> u128 {
+    distinct_palindrome_products(min, max).sum()
+}
+
+/// [`sum_of_palindrome_products`] as a `u64`, or `None` if the sum does not fit.
+pub fn checked_sum_of_palindrome_products(min: u64, max: u64) -> Option<u64> {
+    distinct_palindrome_products(min, max).try_fold(0u64, |sum, p| sum.checked_add(p.into_inner()))
+}
+
+/// The smallest palindromic product of two factors within `min..=max` which has at least `k` factor pairs `(a, b)`
+/// with `a <= b`, as in `11 x 1111 = 101 x 121` for two. A `k` of zero is treated as one.
+///
+/// The palindromes are walked in ascending order as for [`count_palindrome_products`], and the factor pairs of each
+/// are enumerated only until the `k`-th is found, so the search needs constant memory.
+pub fn smallest_with_k_representations(min: u64, max: u64, k: usize) -> Option<Palindrome> {
+    distinct_palindrome_products(min, max).find(|p| p.factor_pairs(min, max).nth(k.max(1) - 1).is_some())
+}
+
+/// The palindromes between `min * min` and `max * max` which are not the product of two factors within `min..=max`,
+/// ascending: the gaps in the coverage of the product span.
+///
+/// Each palindrome from the generator is checked for a factor pair by trial division, as
+/// [`count_palindrome_products`] does, so together the two account for every palindrome in the span.
+pub fn unreachable_palindromes(min: u64, max: u64) -> impl Iterator<Item = Palindrome> {
+    let span = (min <= max).then(|| min.saturating_mul(min)..=max.saturating_mul(max));
+    span.into_iter().flat_map(Palindrome::in_range).filter(move |p| !p.has_factors(min, max))
+}
+
+/// Number of the [`unreachable_palindromes`] of `min..=max`.
+pub fn count_unreachable_palindromes(min: u64, max: u64) -> usize {
+    unreachable_palindromes(min, max).count()
+}
+
+/// Every pair of factors `(a, b)` within `min..=max` with `a <= b` whose product is `value`, ascending in `a`, found
+/// by trial division as for [`Palindrome::factors`], but for any value, palindrome or not.
+///
+/// ```
+/// use palindrome_products::factor_pairs;
+///
+/// assert!(factor_pairs(12, 2, 6).eq([(2, 6), (3, 4)]));
+/// ```
+pub fn factor_pairs(value: u64, min: u64, max: u64) -> impl Iterator<Item = (u64, u64)> {
+    trial_pairs(value, min, max).flatten()
+}
+
+/// One item for each candidate which [`factor_pairs`] tries, in the same order: the pair it completes, if any. Long
+/// trial divisions can be interrupted between any two candidates.
+pub(crate) fn trial_pairs(value: u64, min: u64, max: u64) -> impl Iterator<Item = Option<(u64, u64)>> {
+    // zero is the product of zero with anything
+    let zero_pairs = (value == 0 && min == 0).then_some(0..=max).into_iter().flatten().map(|b| Some((0, b)));
+
+    // the partner of `a` is at most `max`, so `a` is at least `value / max`
+    let first = min.max(1).max(value.div_ceil(max.max(1)));
+    let pairs = (first..=max)
+        .take_while(move |&a| a.checked_mul(a).is_some_and(|square| square <= value))
+        .map(move |a| Some((a, value / a)).filter(|&(_, b)| value.is_multiple_of(a) && b <= max));
+
+    zero_pairs.chain(pairs)
+}
+
+/// The distinct palindromic products of two factors within `min..=max`, ascending.
+fn distinct_palindrome_products(min: u64, max: u64) -> impl Iterator<Item = Palindrome> {
+    Palindrome::in_range(min.saturating_mul(min)..=max.saturating_mul(max)).filter(move |p| p.has_factors(min, max))
+}
+
+/// The median distinct palindromic product of two factors within `min..=max`. When there is an even number of them,
+/// this is the lower of the two middle values, which unlike their mean is always a palindrome.
+///
+/// The palindromes are counted with [`count_palindrome_products`] and the middle one is then found with
+/// [`nth_palindrome_product`], so the sequence is never held in memory.
+pub fn median_palindrome_product(min: u64, max: u64) -> Option<Palindrome> {
+    match count_palindrome_products(min, max) {
+        0 => None,
+        count => nth_palindrome_product(min, max, (count - 1) / 2),
+    }
+}
@@ -0,0 +1,277 @@
+//! Base-generic digit arithmetic shared by the palindrome newtypes.
+//!
+//! Palindromes are generated constructively from their "half": the leading `ceil(len / 2)` digits determine the
+//! rest. Intermediate values are computed in `u128` so that mirroring near `u64::MAX` cannot overflow.
+
+/// Decimal palindromes in a `u64` have at most twenty digits.
+pub(crate) const MAX_DIGITS: u32 = 20;
+
+/// Number of digits of `n` in `base`. Zero has one digit.
+pub(crate) fn digit_count(mut n: u128, base: u128) -> u32 {
+    let mut count = 1;
+    while n >= base {
+        n /= base;
+        count += 1;
+    }
+    count
+}
+
+/// Reverse the digits of `n` in `base`.
+pub(crate) fn reverse(mut n: u128, base: u128) -> u128 {
+    let mut reversed = 0;
+    while n > 0 {
+        reversed = reversed * base + n % base;
+        n /= base;
+    }
+    reversed
+}
+
+/// Reverse the digits of `n` in `base`, or `None` if the reversal does not fit in a `u64`. Trailing zeros become
+/// leading zeros and vanish, so `120` reverses to `21` in base ten.
+///
+/// ```
+/// use palindrome_products::reverse_digits_in_base;
+///
+/// assert_eq!(reverse_digits_in_base(0b1101, 2), Some(0b1011));
+/// assert_eq!(reverse_digits_in_base(u64::MAX - 1, 10), None);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `base` is not within `2..=256`, the bases [`Radix`](crate::Radix) supports: there are no digits in base
+/// zero or one.
+pub fn reverse_digits_in_base(n: u64, base: u32) -> Option<u64> {
+    assert!((2..=256).contains(&base), "base {base} is not within 2..=256");
+    u64::try_from(reverse(n as u128, base as u128)).ok()
+}
+
+/// Build the palindrome with `len` digits whose leading `ceil(len / 2)` digits are `half`.
+pub(crate) fn mirror(half: u128, len: u32, base: u128) -> u128 {
+    half * base.pow(len / 2) + reverse(half / base.pow(len % 2), base)
+}
+
+/// The smallest palindrome in `base` which is greater than or equal to `n`, if it fits in a `u64`.
+pub(crate) fn next_palindrome(n: u64, base: u128) -> Option<u64> {
+    let n = n as u128;
+    if n < base {
+        return Some(n as u64);
+    }
+
+    let len = digit_count(n, base);
+    let half = n / base.pow(len / 2);
+    let candidate = mirror(half, len, base);
+    let next = if candidate >= n {
+        candidate
+    } else if half + 1 == base.pow(len.div_ceil(2)) {
+        // every digit of the half is at its maximum: the next palindrome has one more digit
+        base.pow(len) + 1
+    } else {
+        mirror(half + 1, len, base)
+    };
+
+    u64::try_from(next).ok()
+}
+
+/// The largest palindrome in `base` which is less than or equal to `n`.
+pub(crate) fn prev_palindrome(n: u64, base: u128) -> u64 {
+    let n = n as u128;
+    if n < base {
+        return n as u64;
+    }
+
+    let len = digit_count(n, base);
+    let half = n / base.pow(len / 2);
+    let candidate = mirror(half, len, base);
+    let prev = if candidate <= n {
+        candidate
+    } else if half == base.pow(len.div_ceil(2) - 1) {
+        // the half is at its minimum: the previous palindrome is all maximal digits, one shorter
+        base.pow(len - 1) - 1
+    } else {
+        mirror(half - 1, len, base)
+    };
+
+    // `prev` never exceeds `n`, which came from a `u64`
+    prev as u64
+}
+
+/// The palindromes in `base` within `lo..=hi`, grouped by digit count. Each block is the digit count together with the
+/// first and last half which mirror into the range; every half in between does too.
+#[cfg(feature = "rayon")]
+pub(crate) fn half_blocks(lo: u64, hi: u64, base: u128) -> Vec<(u32, u128, u128)> {
+    if lo > hi {
+        return Vec::new();
+    }
+
+    let mut blocks = Vec::new();
+    for len in digit_count(lo as u128, base)..=digit_count(hi as u128, base) {
+        let smallest_with_len = if len == 1 { 0 } else { base.pow(len - 1) };
+        let block_lo = (lo as u128).max(smallest_with_len);
+        let block_hi = (hi as u128).min(base.pow(len) - 1);
+        // both ends of the block fit in a u64 because they lie within lo..=hi
+        let Some(first) = next_palindrome(block_lo as u64, base) else {
+            break;
+        };
+        let last = prev_palindrome(block_hi as u64, base);
+        if (first as u128) <= block_hi && first <= last {
+            let scale = base.pow(len / 2);
+            blocks.push((len, first as u128 / scale, last as u128 / scale));
+        }
+    }
+    blocks
+}
+
+/// Iterator over the palindromes in `base` within an inclusive range, ascending from the front and descending from
+/// the back, generated without testing the values in between.
+#[derive(Debug, Clone)]
+pub(crate) struct RadixPalindromes {
+    base: u128,
+    front: u64,
+    back: u64,
+    done: bool,
+}
+
+impl RadixPalindromes {
+    pub(crate) fn new(start: u64, end: u64, base: u128) -> RadixPalindromes {
+        let back = prev_palindrome(end, base);
+        match next_palindrome(start, base) {
+            Some(front) => RadixPalindromes { base, front, back, done: front > back },
+            None => RadixPalindromes { base, front: 0, back, done: true },
+        }
+    }
+}
+
+impl Iterator for RadixPalindromes {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let current = self.front;
+        // the back bound is a palindrome, so there is always a successor until the two ends meet
+        match current < self.back {
+            true => self.front = next_palindrome(current + 1, self.base).unwrap_or(self.back),
+            false => self.done = true,
+        }
+        Some(current)
+    }
+}
+
+impl DoubleEndedIterator for RadixPalindromes {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let current = self.back;
+        match current > self.front {
+            true => self.back = prev_palindrome(current - 1, self.base),
+            false => self.done = true,
+        }
+        Some(current)
+    }
+}
+
+/// Iterator over the digits of a number in some base, most significant first, returned by
+/// [`Palindrome::digits_in_base`](crate::Palindrome::digits_in_base). Reverse it for the least significant first.
+#[derive(Debug, Clone)]
+pub struct Digits {
+    value: u128,
+    /// Place value of the next digit from the front; below `low` once every digit has been returned.
+    high: u128,
+    /// Place value of the next digit from the back.
+    low: u128,
+    base: u128,
+}
+
+impl Digits {
+    /// Panics if `base` is not within `2..=256`.
+    pub(crate) fn new(value: u64, base: u32) -> Digits {
+        assert!((2..=256).contains(&base), "base {base} is not within 2..=256");
+        let (value, base) = (value as u128, base as u128);
+        Digits { value, high: base.pow(digit_count(value, base) - 1), low: 1, base }
+    }
+}
+
+impl Iterator for Digits {
+    type Item = u8;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.high < self.low {
+            return None;
+        }
+        let digit = self.value / self.high % self.base;
+        self.high /= self.base;
+        Some(digit as u8)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match self.high < self.low {
+            true => 0,
+            false => digit_count(self.high / self.low, self.base) as usize,
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for Digits {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.high < self.low {
+            return None;
+        }
+        let digit = self.value / self.low % self.base;
+        // at most the base to the power of the digit count, which fits easily for a u64 value
+        self.low *= self.base;
+        Some(digit as u8)
+    }
+}
+
+impl ExactSizeIterator for Digits {}
+
+/// The order in which digits are listed, for methods such as
+/// [`Palindrome::from_digits_ordered`](crate::Palindrome::from_digits_ordered) which take digits in either.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DigitOrder {
+    /// The most significant digit first, as numbers are written: `[1, 2, 3]` is 123.
+    #[default]
+    MostSignificantFirst,
+    /// The least significant digit first, as repeated division produces them: `[1, 2, 3]` is 321.
+    LeastSignificantFirst,
+}
+
+/// Scratch space for the decimal digits of a `u64`, reused across calls to
+/// [`Palindrome::check_with`](crate::Palindrome::check_with) so that hot loops do no per-candidate setup.
+#[derive(Debug, Clone)]
+pub struct DigitBuf([u8; 20]);
+
+impl DigitBuf {
+    /// An empty buffer.
+    pub fn new() -> DigitBuf {
+        DigitBuf([0; 20])
+    }
+
+    /// Fill the buffer with the decimal digits of `value`, least significant first, and return them.
+    pub(crate) fn fill(&mut self, mut value: u64) -> &mut [u8] {
+        let mut len = 0;
+        loop {
+            self.0[len] = (value % 10) as u8;
+            len += 1;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        &mut self.0[..len]
+    }
+
+    /// Whether the decimal digits of `value` read the same in both directions.
+    pub(crate) fn is_palindrome(&mut self, value: u64) -> bool {
+        let digits = self.fill(value);
+        let half = digits.len() / 2;
+        digits.iter().take(half).eq(digits.iter().rev().take(half))
+    }
+}
+
+impl Default for DigitBuf {
+    fn default() -> DigitBuf {
+        DigitBuf::new()
+    }
+}
@@ -0,0 +1,41 @@
+//! The deterministic pseudo-random generator behind every randomized component of the crate.
+//!
+//! Randomized components take their seed explicitly, so the same seed gives the same results on every platform and
+//! in every version which keeps this generator.
+
+/// SplitMix64: tiny, fast and statistically sound for sampling, though not cryptographic.
+#[derive(Debug, Clone)]
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> SplitMix64 {
+        SplitMix64(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `0..bound`, without the bias of a plain remainder. `bound` must be positive.
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        // reject the values above the largest multiple of `bound`
+        let zone = u64::MAX - (u64::MAX - bound + 1) % bound;
+        loop {
+            let value = self.next_u64();
+            if value <= zone {
+                return value % bound;
+            }
+        }
+    }
+    /// A value in `0..=last`, which unlike [`below`](SplitMix64::below) can cover every `u64`.
+    pub(crate) fn up_to(&mut self, last: u64) -> u64 {
+        match last.checked_add(1) {
+            Some(bound) => self.below(bound),
+            None => self.next_u64(),
+        }
+    }
+}
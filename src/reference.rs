@@ -0,0 +1,33 @@
+//! Deliberately simple reference implementations, for differential testing of the optimized algorithms.
+//!
+//! Everything here examines every pair of factors, so it is quadratic in the width of the range and only suitable for
+//! small inputs. The functions favour being obviously correct over being fast.
+
+use crate::Palindrome;
+
+/// Every product `a * b` with `min <= a <= b <= max` which fits in a `u64`, with its factors.
+fn pairs(min: u64, max: u64) -> impl Iterator<Item = (u64, (u64, u64))> {
+    (min..=max).flat_map(move |a| (a..=max).filter_map(move |b| Some((a.checked_mul(b)?, (a, b)))))
+}
+
+/// Reference for [`crate::palindrome_products`].
+pub fn palindrome_products(min: u64, max: u64) -> Option<(Palindrome, Palindrome)> {
+    let palindromes: Vec<Palindrome> = pairs(min, max).filter_map(|(product, _)| Palindrome::new(product)).collect();
+    Some((*palindromes.iter().min()?, *palindromes.iter().max()?))
+}
+
+/// Reference for [`crate::SortedProducts`]: the distinct products in ascending order.
+pub fn sorted_products(min: u64, max: u64) -> Vec<u64> {
+    let mut products: Vec<u64> = pairs(min, max).map(|(product, _)| product).collect();
+    products.sort_unstable();
+    products.dedup();
+    products
+}
+
+/// Reference for [`Palindrome::factors`].
+pub fn factors(value: u64, min: u64, max: u64) -> Vec<(u64, u64)> {
+    pairs(min, max)
+        .filter(|&(product, _)| product == value)
+        .map(|(_, pair)| pair)
+        .collect()
+}
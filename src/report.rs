@@ -0,0 +1,61 @@
+//! Human-readable reports of search results, as Markdown or HTML tables.
+
+use std::fmt::Write;
+
+use crate::{Palindrome, PalindromeProducts};
+
+/// One row per extreme: its label, the palindrome, and its factor pairs.
+fn rows(results: &PalindromeProducts) -> Vec<[String; 3]> {
+    let (min, max) = (results.min(), results.max());
+    [("smallest", results.smallest()), ("largest", results.largest())]
+        .into_iter()
+        .map(|(label, p): (&str, Option<Palindrome>)| match p {
+            Some(p) => [label.to_string(), p.to_string(), format_pairs(&p.factors(min, max))],
+            None => [label.to_string(), "none".to_string(), String::new()],
+        })
+        .collect()
+}
+
+fn format_pairs(pairs: &[(u64, u64)]) -> String {
+    pairs.iter().map(|(a, b)| format!("{a} × {b}")).collect::<Vec<_>>().join(", ")
+}
+
+/// Render a search result as Markdown: a table of the extreme palindromes and their factor pairs, followed by a table
+/// describing the range.
+pub fn render_markdown(results: &PalindromeProducts) -> String {
+    let mut out = String::new();
+    out.push_str("| | palindrome | factor pairs |\n|---|---:|---|\n");
+    for [label, palindrome, pairs] in rows(results) {
+        let _ = writeln!(out, "| {label} | {palindrome} | {pairs} |");
+    }
+    out.push_str("\n| range | factors | factor pairs |\n|---|---:|---:|\n");
+    let _ = writeln!(
+        out,
+        "| {}..={} | {} | {} |",
+        results.min(),
+        results.max(),
+        results.factor_count(),
+        results.factor_pair_count()
+    );
+    out
+}
+
+/// Render a search result as an HTML fragment with the same two tables as [`render_markdown`].
+pub fn render_html(results: &PalindromeProducts) -> String {
+    let mut out = String::new();
+    out.push_str("<table>\n<tr><th></th><th>palindrome</th><th>factor pairs</th></tr>\n");
+    for [label, palindrome, pairs] in rows(results) {
+        let _ = writeln!(out, "<tr><th>{label}</th><td>{palindrome}</td><td>{pairs}</td></tr>");
+    }
+    out.push_str("</table>\n<table>\n<tr><th>range</th><th>factors</th><th>factor pairs</th></tr>\n");
+    let _ = writeln!(
+        out,
+        "<tr><td>{}..={}</td><td>{}</td><td>{}</td></tr>",
+        results.min(),
+        results.max(),
+        results.factor_count(),
+        results.factor_pair_count()
+    );
+    out.push_str("</table>\n");
+    out
+}
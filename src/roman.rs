@@ -0,0 +1,47 @@
+//! Palindromes among Roman numerals, such as XIX or CXC.
+
+/// Largest value representable in standard Roman numerals.
+pub const MAX_ROMAN: u64 = 3999;
+
+const NUMERALS: [(u64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Render `n` in standard subtractive Roman numerals, or `None` if it is outside `1..=3999`.
+pub fn to_roman(mut n: u64) -> Option<String> {
+    if !(1..=MAX_ROMAN).contains(&n) {
+        return None;
+    }
+
+    let mut roman = String::new();
+    for (value, symbol) in NUMERALS {
+        while n >= value {
+            roman.push_str(symbol);
+            n -= value;
+        }
+    }
+    Some(roman)
+}
+
+/// Whether the Roman numeral for `n` reads the same in both directions. Values without a Roman numeral are not
+/// palindromes.
+pub fn is_roman_palindrome(n: u64) -> bool {
+    to_roman(n).is_some_and(|roman| roman.bytes().eq(roman.bytes().rev()))
+}
+
+/// Iterate in ascending order over every value up to 3999 whose Roman numeral is a palindrome.
+pub fn roman_palindromes() -> impl Iterator<Item = u64> {
+    (1..=MAX_ROMAN).filter(|&n| is_roman_palindrome(n))
+}
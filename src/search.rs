@@ -0,0 +1,395 @@
+//! A configurable palindrome product search.
+
+use std::collections::BTreeSet;
+use std::ops::ControlFlow;
+
+use crate::error::SearchError;
+use crate::products::trial_pairs;
+use crate::random::SplitMix64;
+use crate::{
+    palindrome_products_with, Palindrome, PalindromeProducts, PruneReason, SearchObserver, SortedProducts, Strategy,
+    Witness,
+};
+
+/// Seed of the randomized parts of a [`Search`] unless [`Search::seed`] sets another.
+pub const DEFAULT_SEED: u64 = 0x5eed_ba11_0fd1_61e5;
+
+/// Number of candidate factors tried between two calls of [`SearchObserver::on_tick`].
+const TICK: usize = 1 << 16;
+
+/// Builder for a palindrome product search over the factors `min..=max`.
+///
+/// Without further configuration, [`run`](Search::run) gives the same answer as [`PalindromeProducts::new`]; the
+/// options control how that answer is found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search {
+    min: u64,
+    max: u64,
+    strategy: Option<Strategy>,
+    max_memory: Option<usize>,
+    palindromic_factors: bool,
+    step: usize,
+    excluded: BTreeSet<u64>,
+    seed: u64,
+    min_digits: u32,
+}
+
+impl Search {
+    /// Search the factors within `min..=max`.
+    pub fn new(min: u64, max: u64) -> Search {
+        Search {
+            min,
+            max,
+            strategy: None,
+            max_memory: None,
+            palindromic_factors: false,
+            step: 1,
+            excluded: BTreeSet::new(),
+            seed: DEFAULT_SEED,
+            min_digits: 1,
+        }
+    }
+
+    /// Always use `strategy`. By default [`run`](Search::run) picks the one [`Strategy::for_range`] recommends, and
+    /// [`run_observed`](Search::run_observed) uses [`Strategy::ProductFirst`].
+    pub fn strategy(mut self, strategy: Strategy) -> Search {
+        self.strategy = Some(strategy);
+        self
+    }
+
+    /// Limit the working memory of the search to about `bytes`.
+    ///
+    /// When the default strategy would exceed the limit, the search switches to the slower
+    /// [`Strategy::PalindromeFirst`], which needs constant memory. If a strategy was chosen explicitly, exceeding the
+    /// limit is an error instead.
+    pub fn max_memory(mut self, bytes: usize) -> Search {
+        self.max_memory = Some(bytes);
+        self
+    }
+
+    /// Only accept products whose factors are palindromes themselves, such as `11 * 121 = 1331`.
+    pub fn palindromic_factors(mut self, palindromic: bool) -> Search {
+        self.palindromic_factors = palindromic;
+        self
+    }
+
+    /// Only use every `step`-th factor of the range, starting at `min`: the factors are `min, min + step, ..` up to
+    /// `max`, as with `(min..=max).step_by(step)`. A step of two with an odd `min` searches the odd factors only.
+    ///
+    /// Panics if `step` is zero.
+    pub fn step(mut self, step: usize) -> Search {
+        assert!(step > 0, "step must be non-zero");
+        self.step = step;
+        self
+    }
+
+    /// Skip the given factors: no product of an excluded factor is considered, and no returned factor pair contains
+    /// one. Repeated calls add to the exclusions.
+    pub fn exclude_factors(mut self, factors: &[u64]) -> Search {
+        self.excluded.extend(factors);
+        self
+    }
+
+    /// Only accept palindromes with at least `digits` decimal digits, so that `min_digits(2)` skips the trivial
+    /// single digit answers of small ranges. Zero and one accept every palindrome.
+    pub fn min_digits(mut self, digits: u32) -> Search {
+        self.min_digits = digits;
+        self
+    }
+
+    /// Seed the randomized parts of the search, such as [`estimate_density`](Search::estimate_density). The same
+    /// seed and options always give the same results, so runs in CI or experiments are repeatable; without a seed,
+    /// [`DEFAULT_SEED`] is used, which is just as deterministic. The exact search never depends on the seed.
+    pub fn seed(mut self, seed: u64) -> Search {
+        self.seed = seed;
+        self
+    }
+
+    /// Estimate the fraction of factor pairs whose product is a palindrome by testing `samples` pairs of accepted
+    /// factors chosen at random with the seed. `None` if no sampled pair was accepted, as for an empty range.
+    ///
+    /// Pairs are drawn uniformly from the factors on the step of the range; pairs of excluded or, with
+    /// [`palindromic_factors`](Search::palindromic_factors), non-palindromic factors are drawn but not counted.
+    pub fn estimate_density(&self, samples: usize) -> Option<f64> {
+        if self.min > self.max {
+            return None;
+        }
+
+        let mut rng = SplitMix64::new(self.seed);
+        // the index of the last factor on the step, as the count of them overflows for the full range
+        let last = (self.max - self.min) / self.step as u64;
+        let mut factor = || self.min + rng.up_to(last) * self.step as u64;
+        let (mut accepted, mut palindromes) = (0u64, 0u64);
+        for _ in 0..samples {
+            let (a, b) = (factor(), factor());
+            if !self.accepts_value(a) || !self.accepts_value(b) {
+                continue;
+            }
+            accepted += 1;
+            let product = a.checked_mul(b).and_then(Palindrome::new);
+            palindromes += product.is_some_and(|p| self.accepts_palindrome(p)) as u64;
+        }
+        (accepted > 0).then(|| palindromes as f64 / accepted as f64)
+    }
+
+    /// Run the search.
+    pub fn run(&self) -> Result<PalindromeProducts, SearchError> {
+        match self.is_constrained() {
+            true => self.run_observed(&mut ()),
+            false => {
+                let strategy = self.choose_strategy()?;
+                let (smallest, largest) = palindrome_products_with(self.min, self.max, strategy).unzip();
+                Ok(PalindromeProducts::from_parts(self.min, self.max, smallest, largest))
+            }
+        }
+    }
+
+    /// Run the search, reporting its progress to `observer`. The answer is the same as from [`run`](Search::run),
+    /// but the traversal always uses constant memory: the product-first strategy walks the rows of factor pairs,
+    /// pruning each at the best palindrome found so far, rather than enumerating the products in order.
+    pub fn run_observed(&self, observer: &mut dyn SearchObserver) -> Result<PalindromeProducts, SearchError> {
+        // constant memory never exceeds the limit
+        let (smallest, largest) = match self.strategy.unwrap_or_default() {
+            Strategy::ProductFirst => (self.smallest_by_rows(observer)?, self.largest_by_rows(observer)?),
+            Strategy::PalindromeFirst => self.palindrome_first_observed(observer)?,
+        };
+        Ok(PalindromeProducts::from_parts(self.min, self.max, smallest, largest))
+    }
+
+    /// Run the search and return its answer as a [`Witness`], pairing each extreme with the factor pairs this search
+    /// accepts.
+    pub fn witness(&self) -> Result<Witness, SearchError> {
+        let result = self.run()?;
+        let claim = |p: Palindrome| (p, self.factors(p));
+        Ok(Witness::new(result.smallest().map(claim), result.largest().map(claim)))
+    }
+
+    /// Every pair of factors of `p` which this search accepts, in the order of [`Palindrome::factors`].
+    pub fn factors(&self, p: Palindrome) -> Vec<(u64, u64)> {
+        let mut pairs = p.factors(self.min, self.max);
+        pairs.retain(|&(a, b)| self.accepts(a) && self.accepts(b));
+        pairs
+    }
+
+    /// Whether only some of the factors in the range take part in the search.
+    fn is_constrained(&self) -> bool {
+        self.palindromic_factors || self.step > 1 || !self.excluded.is_empty() || self.min_digits > 1
+    }
+
+    /// Whether `factor`, which lies within the range, takes part in the search.
+    fn accepts(&self, factor: u64) -> bool {
+        (factor - self.min).is_multiple_of(self.step as u64) && self.accepts_value(factor)
+    }
+
+    /// Whether a factor on the step of the range takes part in the search.
+    fn accepts_value(&self, factor: u64) -> bool {
+        !self.excluded.contains(&factor) && (!self.palindromic_factors || Palindrome::new(factor).is_some())
+    }
+
+    /// The smallest value with enough digits to be accepted, or `None` if no `u64` has that many.
+    fn smallest_accepted(&self) -> Option<u64> {
+        match self.min_digits {
+            // zero is a single digit too
+            0 | 1 => Some(0),
+            digits => 10u64.checked_pow(digits - 1),
+        }
+    }
+
+    /// Whether `p` has enough digits to be accepted.
+    fn accepts_palindrome(&self, p: Palindrome) -> bool {
+        self.smallest_accepted().is_some_and(|smallest| p >= smallest)
+    }
+
+    /// The accepted factors within `from..=max`, ascending.
+    fn ascending(&self, from: u64) -> impl Iterator<Item = u64> + '_ {
+        // the first factor on the step which is at least `from`, if it is still in range
+        let step = self.step as u64;
+        let offset = from.saturating_sub(self.min).div_ceil(step).checked_mul(step);
+        let first = offset.and_then(|offset| self.min.checked_add(offset)).filter(|&first| first <= self.max);
+        first.into_iter()
+            .flat_map(|first| (first..=self.max).step_by(self.step))
+            .filter(|&factor| self.accepts_value(factor))
+    }
+
+    /// The accepted factors within `min..=to`, descending.
+    fn descending(&self, to: u64) -> impl Iterator<Item = u64> + '_ {
+        // the last factor on the step which is at most `to`
+        let last = (to >= self.min).then(|| to - (to - self.min) % self.step as u64);
+        last.into_iter()
+            .flat_map(|last| (self.min..=last).rev().step_by(self.step))
+            .filter(|&factor| self.accepts_value(factor))
+    }
+
+    /// The smallest palindrome `a * b` of accepted factors `a <= b`, pruning each row at the best product so far.
+    fn smallest_by_rows(&self, observer: &mut dyn SearchObserver) -> Result<Option<Palindrome>, SearchError> {
+        let mut best: Option<Palindrome> = None;
+        for a in self.ascending(self.min) {
+            match a.checked_mul(a) {
+                Some(square) if best.is_none_or(|p| square < p) => {}
+                Some(square) => {
+                    observer.on_pruned(square, PruneReason::Bound);
+                    break;
+                }
+                None => {
+                    observer.on_pruned(u64::MAX, PruneReason::Overflow);
+                    break;
+                }
+            }
+            for b in self.ascending(a) {
+                let Some(product) = a.checked_mul(b) else {
+                    observer.on_pruned(u64::MAX, PruneReason::Overflow);
+                    break;
+                };
+                if best.is_some_and(|p| product >= p) {
+                    observer.on_pruned(product, PruneReason::Bound);
+                    break;
+                }
+                visit(observer, product)?;
+                if let Some(p) = Palindrome::new(product).filter(|&p| self.accepts_palindrome(p)) {
+                    observer.on_palindrome_found(p, &[(a, b)]);
+                    best = Some(p);
+                    break;
+                }
+            }
+        }
+        Ok(best)
+    }
+
+    /// The largest palindrome `a * b` of accepted factors `a <= b`, pruning each row at the best product so far.
+    fn largest_by_rows(&self, observer: &mut dyn SearchObserver) -> Result<Option<Palindrome>, SearchError> {
+        let mut best: Option<Palindrome> = None;
+        for b in self.descending(self.max) {
+            if let Some(square) = b.checked_mul(b).filter(|&square| best.is_some_and(|p| square <= p)) {
+                observer.on_pruned(square, PruneReason::Bound);
+                break;
+            }
+            // skip the partners whose product overflows at once, as smaller ones may still fit
+            let partners = u64::MAX / b.max(1);
+            if partners < b {
+                observer.on_pruned(u64::MAX, PruneReason::Overflow);
+            }
+            for a in self.descending(b.min(partners)) {
+                let product = a * b;
+                if best.is_some_and(|p| product <= p) {
+                    observer.on_pruned(product, PruneReason::Bound);
+                    break;
+                }
+                visit(observer, product)?;
+                if let Some(p) = Palindrome::new(product).filter(|&p| self.accepts_palindrome(p)) {
+                    observer.on_palindrome_found(p, &[(a, b)]);
+                    best = Some(p);
+                    break;
+                }
+            }
+        }
+        Ok(best)
+    }
+
+    /// Walk the palindromes between `min * min` and `max * max` from each end and stop at the first with an accepted
+    /// factor pair.
+    fn palindrome_first_observed(
+        &self,
+        observer: &mut dyn SearchObserver,
+    ) -> Result<(Option<Palindrome>, Option<Palindrome>), SearchError> {
+        if self.min > self.max {
+            return Ok((None, None));
+        }
+
+        let Some(smallest_accepted) = self.smallest_accepted() else {
+            return Ok((None, None));
+        };
+        let lower = self.min.saturating_mul(self.min).max(smallest_accepted);
+        let upper = self.max.saturating_mul(self.max);
+        let Some(smallest) = self.first_with_factors(Palindrome::in_range(lower..=upper), observer)? else {
+            return Ok((None, None));
+        };
+        let largest = self.first_with_factors(Palindrome::in_range(smallest.into_inner()..=upper).rev(), observer)?;
+        Ok((Some(smallest), largest))
+    }
+
+    /// The first of `palindromes` with an accepted factor pair.
+    fn first_with_factors(
+        &self,
+        palindromes: impl Iterator<Item = Palindrome>,
+        observer: &mut dyn SearchObserver,
+    ) -> Result<Option<Palindrome>, SearchError> {
+        for p in palindromes {
+            visit(observer, p.into_inner())?;
+            match self.observed_factors(p, 1, observer)?.is_empty() {
+                true => observer.on_pruned(p.into_inner(), PruneReason::NoFactors),
+                false => {
+                    let factors = self.observed_factors(p, usize::MAX, observer)?;
+                    observer.on_palindrome_found(p, &factors);
+                    return Ok(Some(p));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Up to `limit` of the pairs of factors of `p` which this search accepts, as from [`factors`](Search::factors),
+    /// checking in with `observer` during the trial division.
+    fn observed_factors(
+        &self,
+        p: Palindrome,
+        limit: usize,
+        observer: &mut dyn SearchObserver,
+    ) -> Result<Vec<(u64, u64)>, SearchError> {
+        let mut pairs = Vec::new();
+        for (i, pair) in trial_pairs(p.into_inner(), self.min, self.max).enumerate() {
+            if i % TICK == TICK - 1 {
+                tick(observer)?;
+            }
+            if let Some((a, b)) = pair.filter(|&(a, b)| self.accepts(a) && self.accepts(b)) {
+                pairs.push((a, b));
+                if pairs.len() >= limit {
+                    break;
+                }
+            }
+        }
+        Ok(pairs)
+    }
+
+    fn choose_strategy(&self) -> Result<Strategy, SearchError> {
+        let strategy = self.strategy.unwrap_or_else(|| Strategy::for_range(self.min, self.max));
+        let Some(limit) = self.max_memory else {
+            return Ok(strategy);
+        };
+
+        let required = self.memory_estimate(strategy);
+        match (required <= limit, self.strategy) {
+            (true, _) => Ok(strategy),
+            (false, None) => Ok(Strategy::PalindromeFirst),
+            (false, Some(_)) => Err(SearchError::MemoryLimit { required, limit }),
+        }
+    }
+
+    /// Estimated bytes of working memory which `strategy` needs for this search. Only an unconstrained product-first
+    /// search holds state for every factor, and with 32-bit factors at most a `u32` for each.
+    fn memory_estimate(&self, strategy: Strategy) -> usize {
+        match (self.is_constrained(), strategy) {
+            (false, Strategy::ProductFirst) if u32::try_from(self.max).is_ok() => {
+                let rows = (self.max + 1).saturating_sub(self.min);
+                usize::try_from(rows).unwrap_or(usize::MAX).saturating_mul(size_of::<u32>())
+            }
+            (false, Strategy::ProductFirst) => SortedProducts::memory_estimate(self.min, self.max),
+            _ => 0,
+        }
+    }
+}
+
+/// Report `product` to `observer`, failing if it asks to stop.
+fn visit(observer: &mut dyn SearchObserver, product: u64) -> Result<(), SearchError> {
+    match observer.on_candidate(product) {
+        ControlFlow::Continue(()) => Ok(()),
+        ControlFlow::Break(()) => Err(SearchError::Stopped),
+    }
+}
+
+/// Let `observer` stop a search which is busy between candidates.
+fn tick(observer: &mut dyn SearchObserver) -> Result<(), SearchError> {
+    match observer.on_tick() {
+        ControlFlow::Continue(()) => Ok(()),
+        ControlFlow::Break(()) => Err(SearchError::Stopped),
+    }
+}
@@ -0,0 +1,119 @@
+//! Palindromes within well-known integer sequences.
+
+use std::iter;
+use std::ops::RangeInclusive;
+
+use crate::{DigitBuf, Palindrome};
+
+/// Iterate over the numbers `n` within `range` whose `exp`-th power is a palindrome, paired with that power.
+///
+/// Powers are computed with overflow checks; since `n^exp` grows with `n`, iteration ends at the first `n` whose
+/// power no longer fits in a `u64`.
+pub fn palindromic_powers(exp: u32, range: RangeInclusive<u64>) -> impl Iterator<Item = (u64, Palindrome)> {
+    range
+        .map_while(move |n| Some((n, n.checked_pow(exp)?)))
+        .filter_map(|(n, power)| Some((n, Palindrome::new(power)?)))
+}
+
+/// Iterate over the numbers `n` within `range` whose pronic number `n * (n + 1)` is a palindrome, paired with it, as
+/// for `16 * 17 = 272`.
+///
+/// A pronic number ends in zero whenever `n` ends in 0, 4, 5 or 9, and then it is no palindrome unless it is zero, so
+/// those `n` are skipped without multiplying. Iteration ends at the first `n` whose pronic number does not fit in a
+/// `u64`.
+pub fn palindromic_pronics(range: RangeInclusive<u64>) -> impl Iterator<Item = (u64, Palindrome)> {
+    range
+        .filter(|&n| n == 0 || !matches!(n % 10, 0 | 4 | 5 | 9))
+        .map_while(|n| Some((n, n.checked_mul(n.checked_add(1)?)?)))
+        .filter_map(|(n, pronic)| Some((n, Palindrome::new(pronic)?)))
+}
+
+/// Iterate over the triangular numbers `k * (k + 1) / 2` up to `limit` which are palindromes, paired with their index
+/// `k`, starting from `(0, 0)` and `(1, 1)`.
+///
+/// Each triangular number is the previous one plus its index, accumulated with overflow checks so that iteration ends
+/// at the last one within a `u64`, and checked with a reused [`DigitBuf`].
+pub fn palindromic_triangulars(limit: u64) -> impl Iterator<Item = (u64, Palindrome)> {
+    let mut buf = DigitBuf::new();
+    iter::successors(Some((0u64, 0u64)), |&(k, triangular)| {
+        let k = k.checked_add(1)?;
+        Some((k, triangular.checked_add(k)?))
+    })
+    .take_while(move |&(_, triangular)| triangular <= limit)
+    .filter_map(move |(k, triangular)| Some((k, Palindrome::check_with(&mut buf, triangular)?)))
+}
+
+/// Find the least positive multiple of `n` which is a palindrome no greater than `limit`, together with the factor
+/// `k` such that the palindrome equals `n * k`.
+///
+/// Palindromes are generated in ascending order and tested for divisibility, which visits far fewer candidates than
+/// stepping through the multiples of `n`. Zero and multiples of ten only have multiples ending in zero, which never
+/// qualify.
+pub fn smallest_palindromic_multiple(n: u64, limit: u64) -> Option<(Palindrome, u64)> {
+    if n.is_multiple_of(10) {
+        return None;
+    }
+
+    Palindrome::iter_from(1)
+        .take_while(|p| p.into_inner() <= limit)
+        .find(|p| p.into_inner().is_multiple_of(n))
+        .map(|p| (p, p.into_inner() / n))
+}
+
+/// The palindromes below `limit` which equal a sum of two or more consecutive positive squares, ascending and each
+/// listed once, such as `595 = 6^2 + 7^2 + .. + 12^2`.
+///
+/// The palindromes are generated up front, and a window of consecutive squares slides over each possible first square,
+/// growing until its sum reaches `limit`; every sum is looked up among the palindromes.
+pub fn palindromic_square_sums(limit: u64) -> Vec<Palindrome> {
+    let Some(last) = limit.checked_sub(1) else {
+        return Vec::new();
+    };
+    let palindromes: Vec<Palindrome> = Palindrome::in_range(0..=last).collect();
+    let square = |n: u64| n as u128 * n as u128;
+
+    let mut found = Vec::new();
+    // the shortest window from `first` holds two squares, and it only grows with `first`
+    for first in (1..).take_while(|&first| square(first) + square(first + 1) < limit as u128) {
+        let mut sum = square(first);
+        for next in first + 1.. {
+            sum += square(next);
+            if sum >= limit as u128 {
+                break;
+            }
+            if let Ok(i) = palindromes.binary_search_by_key(&(sum as u64), |p| p.into_inner()) {
+                found.push(palindromes[i]);
+            }
+        }
+    }
+    found.sort();
+    found.dedup();
+    found
+}
+
+/// The longest run of consecutive integers below `limit` which contains no palindrome, as its first integer and its
+/// length. The earliest run wins a tie, and `(0, 0)` means every integer below `limit` is a palindrome.
+///
+/// Runs are the gaps between consecutive palindromes, and the stretch from the last palindrome up to `limit`, so only
+/// the palindromes are visited rather than every integer.
+pub fn longest_palindrome_free_run(limit: u64) -> (u64, u64) {
+    let Some(last) = limit.checked_sub(1) else {
+        return (0, 0);
+    };
+
+    let mut longest = (0, 0);
+    let mut next = 0;
+    let gap_ends = Palindrome::in_range(0..=last).map(Palindrome::into_inner).map(Some).chain([None]);
+    for end in gap_ends {
+        // the run before the palindrome `end`, or up to `limit` after the last one
+        let len = end.unwrap_or(limit) - next;
+        if len > longest.1 {
+            longest = (next, len);
+        }
+        match end.and_then(|end| end.checked_add(1)) {
+            Some(after) => next = after,
+            None => break,
+        }
+    }
+    longest
+}
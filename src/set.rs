@@ -0,0 +1,302 @@
+//! A compact, ordered set of palindromes.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Bound, RangeBounds, Sub};
+
+use crate::radix::{digit_count, mirror, next_palindrome, prev_palindrome, MAX_DIGITS};
+use crate::Palindrome;
+
+/// One bucket per (digit count, leading digit) pair.
+pub(crate) const BUCKETS: usize = MAX_DIGITS as usize * 10;
+
+/// Position of a palindrome in the set: a bucket and the value stored in it.
+pub(crate) type Key = (usize, u32);
+
+/// An ordered set of [`Palindrome`]s which stores only half of each value.
+///
+/// A palindrome is determined by its digit count and its leading `ceil(len / 2)` digits. Palindromes are grouped into
+/// buckets by digit count and leading digit, and each bucket keeps a sorted `Vec<u32>` of the remaining digits of the
+/// half, which always fit in 32 bits. This takes four bytes per palindrome, a fraction of what a `HashSet<u64>`
+/// needs, and keeps the members in ascending order.
+///
+/// Membership tests and range queries are `O(log n)`. Inserting in ascending order is amortized `O(1)`; inserting
+/// elsewhere shifts the tail of one bucket.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PalindromeSet {
+    buckets: Vec<Vec<u32>>,
+    len: usize,
+}
+
+impl PalindromeSet {
+    /// Create an empty set.
+    pub fn new() -> PalindromeSet {
+        PalindromeSet { buckets: vec![Vec::new(); BUCKETS], len: 0 }
+    }
+
+    /// Number of palindromes in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the set contains no palindromes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Add `p` to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, p: Palindrome) -> bool {
+        let (bucket, rest) = key(p);
+        let values = &mut self.buckets[bucket];
+        match values.binary_search(&rest) {
+            Ok(_) => false,
+            Err(i) => {
+                values.insert(i, rest);
+                self.len += 1;
+                true
+            }
+        }
+    }
+
+    /// Remove `p` from the set, returning whether it was present.
+    pub fn remove(&mut self, p: Palindrome) -> bool {
+        let (bucket, rest) = key(p);
+        let values = &mut self.buckets[bucket];
+        match values.binary_search(&rest) {
+            Ok(i) => {
+                values.remove(i);
+                self.len -= 1;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Whether `p` is in the set.
+    pub fn contains(&self, p: Palindrome) -> bool {
+        let (bucket, rest) = key(p);
+        self.buckets[bucket].binary_search(&rest).is_ok()
+    }
+
+    /// Iterate over the palindromes in ascending order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Palindrome> + '_ {
+        self.iter_range(..)
+    }
+
+    /// Iterate in ascending order over the palindromes whose value lies within `range`.
+    pub fn iter_range(&self, range: impl RangeBounds<u64>) -> impl DoubleEndedIterator<Item = Palindrome> + '_ {
+        let (start, end) = self.positions(range).unwrap_or(((0, 0), (0, 0)));
+        (start.0..=end.0).flat_map(move |bucket| {
+            let values = &self.buckets[bucket];
+            let from = if bucket == start.0 { start.1 } else { 0 };
+            let to = if bucket == end.0 { end.1 } else { values.len() };
+            values[from..to].iter().map(move |&rest| value(bucket, rest))
+        })
+    }
+
+    /// Number of palindromes in the set within `lo..=hi`.
+    pub fn count_between(&self, lo: u64, hi: u64) -> usize {
+        let Some((start, end)) = self.positions(lo..=hi) else {
+            return 0;
+        };
+        if start.0 == end.0 {
+            return end.1 - start.1;
+        }
+        let middle: usize = self.buckets[start.0 + 1..end.0].iter().map(Vec::len).sum();
+        self.buckets[start.0].len() - start.1 + middle + end.1
+    }
+
+    /// The palindromes in `self`, in `other`, or in both.
+    pub fn union(&self, other: &PalindromeSet) -> PalindromeSet {
+        self.combine(other, |in_self, in_other| in_self || in_other)
+    }
+
+    /// The palindromes in both `self` and `other`.
+    pub fn intersection(&self, other: &PalindromeSet) -> PalindromeSet {
+        self.combine(other, |in_self, in_other| in_self && in_other)
+    }
+
+    /// The palindromes in `self` but not in `other`.
+    pub fn difference(&self, other: &PalindromeSet) -> PalindromeSet {
+        self.combine(other, |in_self, in_other| in_self && !in_other)
+    }
+
+    /// Merge the buckets of both sets, keeping each value for which `keep` holds given whether it is in `self` and
+    /// whether it is in `other`.
+    fn combine(&self, other: &PalindromeSet, keep: impl Fn(bool, bool) -> bool) -> PalindromeSet {
+        let buckets: Vec<Vec<u32>> = self.buckets.iter().zip(&other.buckets).map(|(a, b)| merge(a, b, &keep)).collect();
+        let len = buckets.iter().map(Vec::len).sum();
+        PalindromeSet { buckets, len }
+    }
+
+    /// Translate a range of values into (bucket, index) positions: the first member at or after the start, and one
+    /// past the last member at or before the end. `None` if no palindrome lies within the range.
+    fn positions(&self, range: impl RangeBounds<u64>) -> Option<((usize, usize), (usize, usize))> {
+        let ((start_bucket, start_rest), (end_bucket, end_rest)) = keys_within(range)?;
+        let start = self.buckets[start_bucket].partition_point(|&r| r < start_rest);
+        let end = self.buckets[end_bucket].partition_point(|&r| r <= end_rest);
+        Some(((start_bucket, start), (end_bucket, end)))
+    }
+}
+
+impl PalindromeSet {
+    /// The per-bucket storage, for serialization.
+    pub(crate) fn buckets(&self) -> &[Vec<u32>] {
+        &self.buckets
+    }
+
+    /// Rebuild a set from per-bucket storage, or `None` if some bucket has the wrong shape, is unsorted, or holds a
+    /// value which does not encode a palindrome of that bucket.
+    pub(crate) fn from_buckets(buckets: Vec<Vec<u32>>) -> Option<PalindromeSet> {
+        if buckets.len() != BUCKETS {
+            return None;
+        }
+        if !buckets.iter().enumerate().all(|(bucket, values)| is_valid_bucket(bucket, values.iter().copied())) {
+            return None;
+        }
+
+        let len = buckets.iter().map(Vec::len).sum();
+        Some(PalindromeSet { buckets, len })
+    }
+}
+
+impl Default for PalindromeSet {
+    fn default() -> Self {
+        PalindromeSet::new()
+    }
+}
+
+impl FromIterator<Palindrome> for PalindromeSet {
+    fn from_iter<I: IntoIterator<Item = Palindrome>>(iter: I) -> PalindromeSet {
+        let mut set = PalindromeSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Palindrome> for PalindromeSet {
+    fn extend<I: IntoIterator<Item = Palindrome>>(&mut self, iter: I) {
+        for p in iter {
+            self.insert(p);
+        }
+    }
+}
+
+impl BitOr for &PalindromeSet {
+    type Output = PalindromeSet;
+
+    /// The union of both sets.
+    fn bitor(self, other: &PalindromeSet) -> PalindromeSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for &PalindromeSet {
+    type Output = PalindromeSet;
+
+    /// The intersection of both sets.
+    fn bitand(self, other: &PalindromeSet) -> PalindromeSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for &PalindromeSet {
+    type Output = PalindromeSet;
+
+    /// The difference of both sets.
+    fn sub(self, other: &PalindromeSet) -> PalindromeSet {
+        self.difference(other)
+    }
+}
+
+impl fmt::Debug for PalindromeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Merge two sorted buckets into the values for which `keep` holds, given whether they are in `a` and whether they are
+/// in `b`, still sorted.
+fn merge(a: &[u32], b: &[u32], keep: impl Fn(bool, bool) -> bool) -> Vec<u32> {
+    let (mut merged, mut i, mut j) = (Vec::new(), 0, 0);
+    while i < a.len() || j < b.len() {
+        let order = match (a.get(i), b.get(j)) {
+            (Some(x), Some(y)) => x.cmp(y),
+            (Some(_), None) => Ordering::Less,
+            _ => Ordering::Greater,
+        };
+        let (value, in_a, in_b) = match order {
+            Ordering::Less => (a[i], true, false),
+            Ordering::Greater => (b[j], false, true),
+            Ordering::Equal => (a[i], true, true),
+        };
+        if keep(in_a, in_b) {
+            merged.push(value);
+        }
+        i += in_a as usize;
+        j += in_b as usize;
+    }
+    merged
+}
+
+/// The keys of the first and the last palindrome within `range`, or `None` if no palindrome lies within it.
+pub(crate) fn keys_within(range: impl RangeBounds<u64>) -> Option<(Key, Key)> {
+    let lo = match range.start_bound() {
+        Bound::Included(&lo) => lo,
+        Bound::Excluded(&lo) => lo.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let hi = match range.end_bound() {
+        Bound::Included(&hi) => hi,
+        Bound::Excluded(&hi) => hi.checked_sub(1)?,
+        Bound::Unbounded => u64::MAX,
+    };
+
+    let first = Palindrome(next_palindrome(lo, 10)?);
+    let last = Palindrome(prev_palindrome(hi, 10));
+    (first <= last).then(|| (key(first), key(last)))
+}
+
+/// Split a palindrome into its bucket and the digits of its half after the leading one.
+pub(crate) fn key(p: Palindrome) -> Key {
+    let n = p.into_inner() as u128;
+    let len = digit_count(n, 10);
+    let half_len = len.div_ceil(2);
+    let half = n / 10u128.pow(len / 2);
+    let scale = 10u128.pow(half_len - 1);
+    let bucket = (len as usize - 1) * 10 + (half / scale) as usize;
+    (bucket, (half % scale) as u32)
+}
+
+/// Rebuild the palindrome stored as `rest` in `bucket`.
+pub(crate) fn value(bucket: usize, rest: u32) -> Palindrome {
+    let len = (bucket / 10) as u32 + 1;
+    let leading = (bucket % 10) as u128;
+    let half = leading * 10u128.pow(len.div_ceil(2) - 1) + rest as u128;
+    Palindrome(mirror(half, len, 10) as u64)
+}
+
+/// Whether `values` are ascending without repeats and each encodes a palindrome of `bucket`.
+pub(crate) fn is_valid_bucket(bucket: usize, values: impl IntoIterator<Item = u32>) -> bool {
+    let limit = bucket_limit(bucket);
+    let mut previous = None;
+    values.into_iter().all(|rest| {
+        let ascending = previous.is_none_or(|previous| previous < rest);
+        previous = Some(rest);
+        ascending && rest < limit
+    })
+}
+
+/// One past the largest value which can be stored in `bucket`: zero if the bucket cannot hold any palindrome.
+pub(crate) fn bucket_limit(bucket: usize) -> u32 {
+    let len = (bucket / 10) as u32 + 1;
+    let leading = bucket % 10;
+    match (len, leading) {
+        (1, _) => 1,
+        (_, 0) => 0,
+        // the 20 digit palindromes in a `u64` range from 10000000000000000001 to 18446744066044764481
+        (MAX_DIGITS, 1) => 844674407,
+        (MAX_DIGITS, _) => 0,
+        _ => 10u32.pow(len.div_ceil(2) - 1),
+    }
+}
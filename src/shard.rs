@@ -0,0 +1,141 @@
+//! Splitting a search into independent shards and merging their results, for distributed runs.
+//!
+//! Every product `a * b` with `a <= b` belongs to exactly one shard: the one whose slice of the range contains its
+//! larger factor `b`. Shards can therefore be searched in any order, on any machine, and merging the results of all
+//! shards produced by one call to [`shard_range`] yields exactly the [`PalindromeProducts`] of the whole range.
+
+use crate::error::MergeError;
+use crate::products::{largest_with_new_factor, smallest_with_new_factor};
+use crate::{Palindrome, PalindromeProducts};
+
+/// One slice of a search: the products `a * b` with `min <= a <= b` and `from <= b <= to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Shard {
+    min: u64,
+    from: u64,
+    to: u64,
+}
+
+impl Shard {
+    /// Describe the shard of a search with minimum factor `min` whose larger factor lies within `from..=to`, e.g. to
+    /// rebuild a shard received from a job queue.
+    pub fn new(min: u64, from: u64, to: u64) -> Shard {
+        Shard { min, from, to }
+    }
+
+    /// The minimum factor of the whole search.
+    pub fn min(&self) -> u64 {
+        self.min
+    }
+
+    /// The smallest larger factor handled by this shard.
+    pub fn from(&self) -> u64 {
+        self.from
+    }
+
+    /// The largest larger factor handled by this shard, which is the maximum of the whole search for the last shard.
+    pub fn to(&self) -> u64 {
+        self.to
+    }
+
+    /// Search this shard.
+    pub fn search(&self) -> ShardResult {
+        ShardResult {
+            shard: *self,
+            smallest: smallest_with_new_factor(self.min, self.from, self.to, None),
+            largest: largest_with_new_factor(self.min, self.from, self.to, None),
+        }
+    }
+}
+
+/// The smallest and largest palindromic products within one [`Shard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ShardResult {
+    shard: Shard,
+    smallest: Option<Palindrome>,
+    largest: Option<Palindrome>,
+}
+
+impl ShardResult {
+    /// Assemble a result computed elsewhere, e.g. when loading it back from storage.
+    pub fn new(shard: Shard, smallest: Option<Palindrome>, largest: Option<Palindrome>) -> ShardResult {
+        ShardResult { shard, smallest, largest }
+    }
+
+    /// The shard which was searched.
+    pub fn shard(&self) -> Shard {
+        self.shard
+    }
+
+    /// The smallest palindromic product within the shard, if any.
+    pub fn smallest(&self) -> Option<Palindrome> {
+        self.smallest
+    }
+
+    /// The largest palindromic product within the shard, if any.
+    pub fn largest(&self) -> Option<Palindrome> {
+        self.largest
+    }
+}
+
+/// Split the search of `min..=max` into at most `shards` shards of roughly equal work.
+///
+/// A larger factor `b` pairs with `b - min + 1` smaller ones, so shards near the top of the range cover fewer values
+/// of `b`. There is always at least one shard, even for an empty range, and never an empty shard otherwise.
+pub fn shard_range(min: u64, max: u64, shards: usize) -> Vec<Shard> {
+    if min > max {
+        return vec![Shard::new(min, min, max)];
+    }
+
+    // the work up to larger factor `min + t - 1` is the triangular number t(t+1)/2, so boundaries at equal work
+    // fall where t is proportional to the square root of the shard index
+    let width = (max - min) as f64 + 1.0;
+    let shards = shards.max(1);
+    let mut result = Vec::with_capacity(shards);
+    let mut from = min;
+    for i in 1..=shards {
+        let to = match i {
+            i if i == shards => max,
+            i => min + ((width * (i as f64 / shards as f64).sqrt()) as u64).saturating_sub(1),
+        };
+        if to >= from {
+            result.push(Shard::new(min, from, to));
+            match to.checked_add(1) {
+                Some(next) => from = next,
+                None => break,
+            }
+        }
+    }
+    result
+}
+
+/// Combine the results of every shard of a search into the answer for the whole range.
+///
+/// The results may arrive in any order. When they are the results of all the shards returned by one call to
+/// [`shard_range`], the merged answer is guaranteed to equal [`PalindromeProducts::new`] on the whole range. Missing
+/// or overlapping shards are reported as an error rather than silently producing a different answer.
+pub fn merge_shard_results(results: impl IntoIterator<Item = ShardResult>) -> Result<PalindromeProducts, MergeError> {
+    let mut results: Vec<ShardResult> = results.into_iter().collect();
+    results.sort_by_key(|result| result.shard.from);
+    let first = results.first().ok_or(MergeError::NoShards)?.shard;
+
+    // `None` once a shard has reached `u64::MAX`, after which nothing can follow
+    let mut expected_from = Some(first.min);
+    for shard in results.iter().map(ShardResult::shard) {
+        if shard.min != first.min {
+            return Err(MergeError::MismatchedMin { expected: first.min, found: shard.min });
+        }
+        if expected_from != Some(shard.from) {
+            let expected_from = expected_from.unwrap_or(u64::MAX);
+            return Err(MergeError::NotContiguous { expected_from, found_from: shard.from });
+        }
+        expected_from = shard.to.checked_add(1);
+    }
+
+    let max = results.last().map_or(first.to, |result| result.shard.to);
+    let smallest = results.iter().filter_map(|result| result.smallest).min();
+    let largest = results.iter().filter_map(|result| result.largest).max();
+    Ok(PalindromeProducts::from_parts(first.min, max, smallest, largest))
+}
@@ -0,0 +1,92 @@
+//! Alternative algorithms for the palindrome product search.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{product_first, Palindrome};
+
+/// How [`palindrome_products_with`] looks for the extreme palindromes. Every strategy returns the same answer; they
+/// differ in which ranges they handle quickly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Strategy {
+    /// Walk the products of the range in order from each end with [`SortedProducts`](crate::SortedProducts) and stop at the first
+    /// palindrome. Fast when palindromic products are dense, as for narrow ranges.
+    #[default]
+    ProductFirst,
+    /// Walk the palindromes between `min * min` and `max * max` from each end and stop at the first with a factor
+    /// pair in the range. Fast for wide ranges, where there are far fewer palindromes than products.
+    PalindromeFirst,
+}
+
+/// Ranges with at least this many factors are searched palindrome-first unless a strategy is chosen. Recorded with
+/// `palindrome-products bench` on one core of a release build:
+///
+/// | factors                 | product-first | palindrome-first |
+/// |-------------------------|---------------|------------------|
+/// | `15..=15`               | 69 ns         | 105 ns           |
+/// | `100..=110`             | 1.7 µs        | 1.6 µs           |
+/// | `100..=999`             | 311 µs        | 14 µs            |
+/// | `5000000..=5000050`     | 99 µs         | 9.8 µs           |
+/// | `100000..=999999`       | 301 ms        | 1.1 ms           |
+/// | `1000000..=9999999`     | 22.8 s        | 16.7 ms          |
+/// | `4294960000..=u32::MAX` | over 60 s     | 60 ms            |
+///
+/// Walking the products only pays off for a handful of factors, where there are hardly any products to walk.
+const PALINDROME_FIRST_FACTORS: u64 = 16;
+
+impl Strategy {
+    /// Every strategy.
+    pub const ALL: [Strategy; 2] = [Strategy::ProductFirst, Strategy::PalindromeFirst];
+
+    /// The strategy which [`palindrome_products`](crate::palindrome_products) and a [`Search`](crate::Search) without
+    /// a chosen strategy use for `min..=max`: product-first for fewer than 16 factors, palindrome-first otherwise,
+    /// following benchmarks of both.
+    pub fn for_range(min: u64, max: u64) -> Strategy {
+        match (max as u128 + 1).saturating_sub(min as u128) < PALINDROME_FIRST_FACTORS as u128 {
+            true => Strategy::ProductFirst,
+            false => Strategy::PalindromeFirst,
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Strategy::ProductFirst => "product-first",
+            Strategy::PalindromeFirst => "palindrome-first",
+        })
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Strategy::ALL
+            .into_iter()
+            .find(|strategy| strategy.to_string() == s)
+            .ok_or_else(|| format!("unknown strategy {s:?}"))
+    }
+}
+
+/// [`palindrome_products`](crate::palindrome_products) using the given strategy.
+pub fn palindrome_products_with(min: u64, max: u64, strategy: Strategy) -> Option<(Palindrome, Palindrome)> {
+    match strategy {
+        Strategy::ProductFirst => product_first(min, max),
+        Strategy::PalindromeFirst => palindrome_first(min, max),
+    }
+}
+
+fn palindrome_first(min: u64, max: u64) -> Option<(Palindrome, Palindrome)> {
+    if min > max {
+        return None;
+    }
+
+    let (lower, upper) = (min.saturating_mul(min), max.saturating_mul(max));
+    let has_factors = |p: &Palindrome| p.has_factors(min, max);
+
+    let smallest = Palindrome::in_range(lower..=upper).find(has_factors)?;
+    let largest = Palindrome::in_range(smallest.into_inner()..=upper).rfind(has_factors)?;
+    Some((smallest, largest))
+}
@@ -0,0 +1,80 @@
+//! Palindrome checks over streams of digits or bytes which are never held in memory.
+
+use crate::random::SplitMix64;
+use crate::DEFAULT_SEED;
+
+/// The Mersenne prime 2^61 - 1, the modulus of the hashes.
+const MODULUS: u64 = (1 << 61) - 1;
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    (a as u128 * b as u128 % MODULUS as u128) as u64
+}
+
+/// Tracks whether the digits or bytes consumed so far read the same in both directions, in constant memory.
+///
+/// Each symbol updates two polynomial hashes, one reading the sequence forwards and one backwards; the sequence is
+/// taken to be a palindrome when they agree. Distinct sequences of length `n` collide with probability at most
+/// `n / 2^61` over the choice of hash base, which is drawn from the seed: the same seed always gives the same answers,
+/// and a secret one keeps adversarial inputs from forcing a false positive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamChecker {
+    base: u64,
+    forward: u64,
+    backward: u64,
+    /// `base` to the power of the number of symbols so far.
+    power: u64,
+    len: u64,
+}
+
+impl StreamChecker {
+    /// A checker of an empty stream, hashing with the base drawn from [`DEFAULT_SEED`].
+    pub fn new() -> StreamChecker {
+        StreamChecker::with_seed(DEFAULT_SEED)
+    }
+
+    /// A checker of an empty stream, hashing with a base drawn from `seed`.
+    pub fn with_seed(seed: u64) -> StreamChecker {
+        // bases below 256 would let short sequences collide outright
+        let base = 256 + SplitMix64::new(seed).below(MODULUS - 256);
+        StreamChecker { base, forward: 0, backward: 0, power: 1, len: 0 }
+    }
+
+    /// Consume the next digit or byte of the stream.
+    pub fn push(&mut self, symbol: u8) {
+        // offset the symbols so that zeros are not invisible to the hashes
+        let symbol = symbol as u64 + 1;
+        self.forward = (mul_mod(self.forward, self.base) + symbol) % MODULUS;
+        self.backward = (self.backward + mul_mod(symbol, self.power)) % MODULUS;
+        self.power = mul_mod(self.power, self.base);
+        self.len += 1;
+    }
+
+    /// Whether the stream so far reads the same in both directions. An empty stream does.
+    pub fn is_palindrome(&self) -> bool {
+        self.forward == self.backward
+    }
+
+    /// The number of symbols consumed.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether no symbol has been consumed.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Default for StreamChecker {
+    fn default() -> StreamChecker {
+        StreamChecker::new()
+    }
+}
+
+impl Extend<u8> for StreamChecker {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, symbols: T) {
+        for symbol in symbols {
+            self.push(symbol);
+        }
+    }
+}
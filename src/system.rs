@@ -0,0 +1,227 @@
+//! Pluggable definitions of what the digits of a number are, for palindromes beyond plain base ten.
+
+use crate::radix::Digits;
+
+/// A way of writing numbers as a sequence of digits, which decides what it means for a number to be a palindrome in
+/// it: its digits, after normalization, read the same in both directions when compared with [`same`].
+///
+/// ```
+/// use palindrome_products::{DigitSystem, Palindrome};
+///
+/// /// Decimal digits, but read on a seven-segment display turned upside down: 6 and 9 look alike.
+/// struct Display;
+///
+/// impl DigitSystem for Display {
+///     type Digit = u8;
+///
+///     fn digits(&self, value: u64) -> Vec<u8> {
+///         value.to_string().bytes().map(|b| b - b'0').collect()
+///     }
+///
+///     fn same(&self, a: &u8, b: &u8) -> bool {
+///         a == b || matches!((a, b), (6, 9) | (9, 6))
+///     }
+/// }
+///
+/// assert_eq!(Palindrome::new_with(&Display, 619), Some(619));
+/// assert_eq!(Palindrome::new_with(&Display, 618), None);
+/// ```
+///
+/// [`same`]: DigitSystem::same
+pub trait DigitSystem {
+    /// A single digit.
+    type Digit: PartialEq;
+
+    /// The digits of `value`, most significant first.
+    fn digits(&self, value: u64) -> Vec<Self::Digit>;
+
+    /// Whether two digits count as the same when reading backwards. Equality unless overridden.
+    fn same(&self, a: &Self::Digit, b: &Self::Digit) -> bool {
+        a == b
+    }
+
+    /// Rewrite the digits into a canonical form before they are compared, such as by dropping padding. Nothing
+    /// unless overridden.
+    fn normalize(&self, digits: &mut Vec<Self::Digit>) {
+        let _ = digits;
+    }
+
+    /// Whether `value` reads the same in both directions in this system.
+    fn is_palindrome(&self, value: u64) -> bool {
+        let mut digits = self.digits(value);
+        self.normalize(&mut digits);
+        let half = digits.len() / 2;
+        digits.iter().take(half).zip(digits.iter().rev()).all(|(a, b)| self.same(a, b))
+    }
+}
+
+impl<S: DigitSystem + ?Sized> DigitSystem for &S {
+    type Digit = S::Digit;
+
+    fn digits(&self, value: u64) -> Vec<Self::Digit> {
+        (**self).digits(value)
+    }
+
+    fn same(&self, a: &Self::Digit, b: &Self::Digit) -> bool {
+        (**self).same(a, b)
+    }
+
+    fn normalize(&self, digits: &mut Vec<Self::Digit>) {
+        (**self).normalize(digits)
+    }
+
+    fn is_palindrome(&self, value: u64) -> bool {
+        (**self).is_palindrome(value)
+    }
+}
+
+/// The ordinary digits of some base within `2..=256`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Radix(u32);
+
+impl Radix {
+    /// Base ten.
+    pub const DECIMAL: Radix = Radix(10);
+
+    /// Panics if `base` is not within `2..=256`.
+    pub fn new(base: u32) -> Radix {
+        assert!((2..=256).contains(&base), "base {base} is not within 2..=256");
+        Radix(base)
+    }
+
+    /// The base of these digits.
+    pub fn base(&self) -> u32 {
+        self.0
+    }
+}
+
+impl DigitSystem for Radix {
+    type Digit = u8;
+
+    fn digits(&self, value: u64) -> Vec<u8> {
+        Digits::new(value, self.0).collect()
+    }
+}
+
+/// Balanced ternary: base three with the digits -1, 0 and 1, so that 8 is written `10-` (9 - 1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BalancedTernary;
+
+impl DigitSystem for BalancedTernary {
+    type Digit = i8;
+
+    fn digits(&self, mut value: u64) -> Vec<i8> {
+        let mut digits = Vec::new();
+        loop {
+            match value % 3 {
+                // two is written as three minus one
+                2 => {
+                    digits.push(-1);
+                    value = value / 3 + 1;
+                }
+                digit => {
+                    digits.push(digit as i8);
+                    value /= 3;
+                }
+            }
+            if value == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        digits
+    }
+}
+
+/// The digits of another system taken in groups of `width`, counted from the least significant end, so that a
+/// palindrome reads the same group by group: `12_34_12` is one in pairs of decimal digits.
+///
+/// The leading group is padded with the digit returned by `pad` if the digit count is not a multiple of `width`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grouped<S: DigitSystem> {
+    system: S,
+    width: usize,
+    pad: S::Digit,
+}
+
+impl<S: DigitSystem> Grouped<S> {
+    /// Group the digits of `system` by `width`, padding the leading group with `pad`. Panics if `width` is zero.
+    pub fn new(system: S, width: usize, pad: S::Digit) -> Grouped<S> {
+        assert!(width > 0, "groups must hold at least one digit");
+        Grouped { system, width, pad }
+    }
+}
+
+impl<S: DigitSystem> DigitSystem for Grouped<S>
+where
+    S::Digit: Clone,
+{
+    type Digit = Vec<S::Digit>;
+
+    fn digits(&self, value: u64) -> Vec<Vec<S::Digit>> {
+        let mut digits = self.system.digits(value);
+        self.system.normalize(&mut digits);
+        let padding = (self.width - digits.len() % self.width) % self.width;
+        let padded: Vec<S::Digit> = std::iter::repeat_n(self.pad.clone(), padding).chain(digits).collect();
+        padded.chunks(self.width).map(<[S::Digit]>::to_vec).collect()
+    }
+
+    fn same(&self, a: &Vec<S::Digit>, b: &Vec<S::Digit>) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| self.system.same(a, b))
+    }
+}
+
+/// The digits of another system padded at the front to a fixed width, as in identifiers with fixed-width fields:
+/// `010` is a three-digit palindrome, though 10 is not one.
+///
+/// Values with more than `width` digits do not fit the field and are never palindromes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedWidth<S: DigitSystem> {
+    system: S,
+    width: usize,
+    pad: S::Digit,
+}
+
+impl<S: DigitSystem> FixedWidth<S> {
+    /// Pad the digits of `system` to `width` with `pad`.
+    pub fn new(system: S, width: usize, pad: S::Digit) -> FixedWidth<S> {
+        FixedWidth { system, width, pad }
+    }
+
+    /// The number of digits every value is padded to.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl FixedWidth<Radix> {
+    /// Decimal digits padded with zeros to `width`.
+    pub fn zero_padded(width: usize) -> FixedWidth<Radix> {
+        FixedWidth::new(Radix::DECIMAL, width, 0)
+    }
+}
+
+impl<S: DigitSystem> DigitSystem for FixedWidth<S>
+where
+    S::Digit: Clone,
+{
+    type Digit = S::Digit;
+
+    /// The digits of `value` in the wrapped system, padded to the width unless there are more of them already.
+    fn digits(&self, value: u64) -> Vec<S::Digit> {
+        let mut digits = self.system.digits(value);
+        self.system.normalize(&mut digits);
+        let padding = self.width.saturating_sub(digits.len());
+        std::iter::repeat_n(self.pad.clone(), padding).chain(digits).collect()
+    }
+
+    fn same(&self, a: &S::Digit, b: &S::Digit) -> bool {
+        self.system.same(a, b)
+    }
+
+    fn is_palindrome(&self, value: u64) -> bool {
+        let digits = self.digits(value);
+        let half = digits.len() / 2;
+        digits.len() <= self.width && digits.iter().take(half).zip(digits.iter().rev()).all(|(a, b)| self.same(a, b))
+    }
+}
@@ -0,0 +1,116 @@
+//! Generating palindrome tables and persisting them to disk.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::set::BUCKETS;
+use crate::{Palindrome, PalindromeSet};
+
+pub(crate) const MAGIC: [u8; 4] = *b"PALT";
+pub(crate) const VERSION: u32 = 1;
+
+/// Size of the header preceding the values.
+pub(crate) const HEADER_LEN: usize = 8 + BUCKETS * 8;
+
+impl PalindromeSet {
+    /// Every palindrome strictly below `bound`.
+    pub fn below(bound: u64) -> PalindromeSet {
+        let mut set = PalindromeSet::new();
+        for p in Palindrome::iter_from(0).take_while(|p| p.into_inner() < bound) {
+            set.insert(p);
+        }
+        set
+    }
+
+    /// Every palindrome with at most `digits` digits.
+    pub fn up_to_digits(digits: u32) -> PalindromeSet {
+        match 10u64.checked_pow(digits) {
+            // zero still has one digit
+            Some(1) => PalindromeSet::new(),
+            Some(bound) => PalindromeSet::below(bound),
+            None => PalindromeSet::below(u64::MAX),
+        }
+    }
+
+    /// Write the set as a table which [`read_from`](PalindromeSet::read_from) can load without regenerating or
+    /// re-sorting anything. The format stores each palindrome in its compact half-value layout, all integers
+    /// little-endian:
+    ///
+    /// | bytes            | content                                                 |
+    /// |------------------|---------------------------------------------------------|
+    /// | 4                | magic `PALT`                                            |
+    /// | 4                | format version, currently `1`                           |
+    /// | 200 × 8          | number of values in each bucket                         |
+    /// | 4 per palindrome | the stored half of each palindrome, bucket after bucket |
+    ///
+    /// Buckets are ordered by digit count and then leading digit, and values within a bucket are ascending, so the
+    /// file lists the palindromes in ascending order.
+    pub fn write_to(&self, writer: impl Write) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        for values in self.buckets() {
+            writer.write_all(&(values.len() as u64).to_le_bytes())?;
+        }
+        for &rest in self.buckets().iter().flatten() {
+            writer.write_all(&rest.to_le_bytes())?;
+        }
+        writer.flush()
+    }
+
+    /// Read a set written by [`write_to`](PalindromeSet::write_to). Malformed input is reported as
+    /// [`io::ErrorKind::InvalidData`].
+    pub fn read_from(reader: impl Read) -> io::Result<PalindromeSet> {
+        let mut reader = BufReader::new(reader);
+        let mut header = vec![0; HEADER_LEN];
+        reader.read_exact(&mut header)?;
+        let lengths = parse_header(&header)?;
+
+        let mut buckets = Vec::with_capacity(BUCKETS);
+        let mut word = [0; 4];
+        for len in lengths {
+            let mut values = Vec::new();
+            for _ in 0..len {
+                reader.read_exact(&mut word)?;
+                values.push(u32::from_le_bytes(word));
+            }
+            buckets.push(values);
+        }
+        if reader.read(&mut word)? != 0 {
+            return Err(invalid_data("trailing bytes after palindrome table"));
+        }
+
+        PalindromeSet::from_buckets(buckets).ok_or_else(|| invalid_data("palindrome table values are malformed"))
+    }
+
+    /// Write the set to the file at `path`, replacing it if it exists.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.write_to(File::create(path)?)
+    }
+
+    /// Read a set from the file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<PalindromeSet> {
+        PalindromeSet::read_from(File::open(path)?)
+    }
+}
+
+/// Check the magic and version, returning the number of values in each bucket.
+pub(crate) fn parse_header(header: &[u8]) -> io::Result<Vec<u64>> {
+    if header.len() < HEADER_LEN || header[..4] != MAGIC {
+        return Err(invalid_data("not a palindrome table"));
+    }
+    let version = u32::from_le_bytes(header[4..8].try_into().unwrap_or_default());
+    if version != VERSION {
+        return Err(invalid_data("unsupported palindrome table version"));
+    }
+
+    Ok(header[8..HEADER_LEN]
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap_or_default()))
+        .collect())
+}
+
+pub(crate) fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,399 @@
+//! Palindrome utilities for strings.
+//!
+//! All functions operate on `char`s as given. Callers who want to ignore case, whitespace and punctuation
+//! (so that "Taco cat" counts as a palindrome) should pass the input through [`normalize`] first.
+
+use std::collections::BTreeMap;
+
+/// Lowercase `s` and drop every character which is not alphanumeric.
+pub fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Count the occurrences of each character, in a deterministic order.
+fn char_counts(s: &str) -> BTreeMap<char, usize> {
+    let mut counts = BTreeMap::new();
+    for c in s.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Whether the characters of `s` can be rearranged into a palindrome, i.e. at most one character occurs an odd
+/// number of times.
+pub fn can_form_palindrome(s: &str) -> bool {
+    char_counts(s).values().filter(|&&n| n % 2 == 1).count() <= 1
+}
+
+/// Rearrange the characters of `s` into a palindrome, or `None` if that is impossible.
+///
+/// The result is deterministic: the first half lists the characters in ascending order, followed by the odd
+/// character (if any) and the mirrored first half.
+pub fn palindromic_permutation(s: &str) -> Option<String> {
+    let counts = char_counts(s);
+    let mut middle = None;
+    let mut half = String::with_capacity(s.len() / 2);
+
+    for (&c, &n) in &counts {
+        if n % 2 == 1 {
+            if middle.is_some() {
+                return None;
+            }
+            middle = Some(c);
+        }
+        half.extend(std::iter::repeat_n(c, n / 2));
+    }
+
+    let mut result = half.clone();
+    result.extend(middle);
+    result.extend(half.chars().rev());
+    Some(result)
+}
+
+/// The KMP failure function: for each prefix of `s`, the length of its longest proper prefix which is also a suffix.
+fn failure<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let mut failure = vec![0; s.len()];
+    for i in 1..s.len() {
+        let mut k = failure[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = failure[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        failure[i] = k;
+    }
+    failure
+}
+
+/// The length in `char`s of the longest prefix of `forward` which reads the same backwards, where `backward` is
+/// `forward` reversed: the longest border of `forward`, a separator, then `backward`.
+fn palindromic_prefix_len(forward: impl Iterator<Item = char>, backward: impl Iterator<Item = char>) -> usize {
+    let joined: Vec<Option<char>> = forward.map(Some).chain([None]).chain(backward.map(Some)).collect();
+    failure(&joined).last().copied().unwrap_or(0)
+}
+
+/// The longest prefix of `s` which is a palindrome, found in linear time with the KMP failure function. Empty only
+/// for an empty `s`.
+pub fn longest_palindromic_prefix(s: &str) -> &str {
+    let len = palindromic_prefix_len(s.chars(), s.chars().rev());
+    let end = s.char_indices().nth(len).map_or(s.len(), |(i, _)| i);
+    &s[..end]
+}
+
+/// The longest suffix of `s` which is a palindrome, as [`longest_palindromic_prefix`] of the reversed string.
+pub fn longest_palindromic_suffix(s: &str) -> &str {
+    let len = palindromic_prefix_len(s.chars().rev(), s.chars());
+    let start = s.char_indices().rev().take(len).last().map_or(s.len(), |(i, _)| i);
+    &s[start..]
+}
+
+/// The shortest palindrome which ends with `s`, made by prepending the reversal of whatever follows the longest
+/// palindromic prefix: `"abcd"` becomes `"dcbabcd"`.
+pub fn shortest_palindrome(s: &str) -> String {
+    let rest = &s[longest_palindromic_prefix(s).len()..];
+    rest.chars().rev().chain(s.chars()).collect()
+}
+
+/// Whether `chars` read the same in both directions.
+fn is_palindrome(chars: &[char]) -> bool {
+    chars.iter().eq(chars.iter().rev())
+}
+
+/// Manacher's algorithm: for each of the `2n + 1` centers of `chars`, between and on the characters, the length of
+/// the longest palindrome around it. Each center reuses the mirror of an earlier one inside the palindrome reaching
+/// furthest right, so the whole takes linear time.
+fn palindrome_lengths(chars: &[char]) -> Vec<usize> {
+    // odd positions are the characters, even positions the gaps around them
+    let padded: Vec<Option<char>> = (0..2 * chars.len() + 1).map(|i| (i % 2 == 1).then(|| chars[i / 2])).collect();
+    let mut radii = vec![0usize; padded.len()];
+    let (mut center, mut right) = (0, 0);
+    for i in 0..padded.len() {
+        let mut radius = match i < right {
+            true => radii[2 * center - i].min(right - i),
+            false => 0,
+        };
+        while i > radius && i + radius + 1 < padded.len() && padded[i - radius - 1] == padded[i + radius + 1] {
+            radius += 1;
+        }
+        radii[i] = radius;
+        if i + radius > right {
+            (center, right) = (i, i + radius);
+        }
+    }
+    // a radius in the padded string is the length of the palindrome in the original
+    radii
+}
+
+/// The number of non-empty substrings of `s` which are palindromes, counting each position separately, so `"aaa"`
+/// has six. Linear in the length of `s`.
+pub fn count_palindromic_substrings(s: &str) -> usize {
+    let chars: Vec<char> = s.chars().collect();
+    // the palindromes around a center are those of its longest, trimmed a character at each end at a time
+    palindrome_lengths(&chars).iter().map(|len| len.div_ceil(2)).sum()
+}
+
+/// The longest substring of `s` which is a palindrome, the first of them if several are as long. Linear in the
+/// length of `s`.
+pub fn longest_palindromic_substring(s: &str) -> &str {
+    let chars: Vec<char> = s.chars().collect();
+    let lengths = palindrome_lengths(&chars);
+    // the first maximum, as max_by_key keeps the last
+    let (center, &len) = lengths.iter().enumerate().rev().max_by_key(|&(_, len)| len).unwrap_or((0, &0));
+    let start = (center - len) / 2;
+    let byte = |index: usize| s.char_indices().nth(index).map_or(s.len(), |(i, _)| i);
+    &s[byte(start)..byte(start + len)]
+}
+
+/// For each end position `j` of `chars`, the fewest palindromes which `chars[..j]` splits into and the start of the
+/// last of them, filled in over every palindromic substring in quadratic time.
+fn palindrome_partitions(chars: &[char]) -> Vec<(usize, usize)> {
+    let n = chars.len();
+    // palindromic[i][j]: whether chars[i..j] is a palindrome, for ever longer spans
+    let mut palindromic = vec![vec![false; n + 1]; n + 1];
+    let mut best = vec![(0, 0); n + 1];
+    for j in 1..=n {
+        best[j] = (usize::MAX, 0);
+        for i in (0..j).rev() {
+            palindromic[i][j] = chars[i] == chars[j - 1] && (j - i <= 2 || palindromic[i + 1][j - 1]);
+            if palindromic[i][j] && best[i].0 < best[j].0 {
+                best[j] = (best[i].0 + 1, i);
+            }
+        }
+    }
+    best
+}
+
+/// The fewest cuts which split `s` into palindromes: zero for a palindrome, one for `"aab"` as `"aa" | "b"`.
+pub fn min_palindrome_cuts(s: &str) -> usize {
+    let chars: Vec<char> = s.chars().collect();
+    palindrome_partitions(&chars)[chars.len()].0.saturating_sub(1)
+}
+
+/// A split of `s` into the fewest palindromes, as found by [`min_palindrome_cuts`]. Empty for an empty `s`.
+pub fn min_palindrome_partition(s: &str) -> Vec<&str> {
+    let chars: Vec<char> = s.chars().collect();
+    let best = palindrome_partitions(&chars);
+    let mut bytes: Vec<usize> = s.char_indices().map(|(i, _)| i).collect();
+    bytes.push(s.len());
+
+    let mut parts = Vec::new();
+    let mut end = chars.len();
+    while end > 0 {
+        let start = best[end].1;
+        parts.push(&s[bytes[start]..bytes[end]]);
+        end = start;
+    }
+    parts.reverse();
+    parts
+}
+
+/// A node of the trie of reversed words used by [`palindrome_pairs`].
+#[derive(Default)]
+struct TrieNode {
+    children: BTreeMap<char, usize>,
+    /// The words whose reversal ends at this node.
+    words: Vec<usize>,
+    /// The words whose reversal continues below this node with a palindrome.
+    palindromic_rest: Vec<usize>,
+}
+
+/// Every pair of distinct indices `(i, j)` such that `words[i]` followed by `words[j]` is a palindrome, sorted.
+///
+/// The reversed words go into a trie, and each word is then walked down it once, so this takes time proportional to
+/// the total length of the words times the length of the longest rather than testing every pair.
+pub fn palindrome_pairs(words: &[&str]) -> Vec<(usize, usize)> {
+    let words: Vec<Vec<char>> = words.iter().map(|w| w.chars().collect()).collect();
+    let mut trie = vec![TrieNode::default()];
+    for (k, word) in words.iter().enumerate() {
+        let mut node = 0;
+        for end in (0..word.len()).rev() {
+            if is_palindrome(&word[..=end]) {
+                trie[node].palindromic_rest.push(k);
+            }
+            node = match trie[node].children.get(&word[end]) {
+                Some(&child) => child,
+                None => {
+                    trie.push(TrieNode::default());
+                    let child = trie.len() - 1;
+                    trie[node].children.insert(word[end], child);
+                    child
+                }
+            };
+        }
+        trie[node].words.push(k);
+    }
+
+    let mut pairs = Vec::new();
+    for (i, word) in words.iter().enumerate() {
+        let mut node = Some(0);
+        for start in 0..word.len() {
+            let Some(current) = node else {
+                break;
+            };
+            // another word is the reversal of this one's start, and the rest is a palindrome itself
+            if is_palindrome(&word[start..]) {
+                pairs.extend(trie[current].words.iter().filter(|&&k| k != i).map(|&k| (i, k)));
+            }
+            node = trie[current].children.get(&word[start]).copied();
+        }
+        // the whole word matched: another word is its reversal, or its reversal followed by a palindrome
+        if let Some(current) = node {
+            let matches = trie[current].words.iter().chain(&trie[current].palindromic_rest);
+            pairs.extend(matches.filter(|&&k| k != i).map(|&k| (i, k)));
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// A node of an [`Eertree`]: one distinct palindrome.
+#[derive(Debug, Clone)]
+struct EertreeNode {
+    /// The length of the palindrome, with -1 for the imaginary root below the single characters.
+    len: isize,
+    /// The node of the longest proper palindromic suffix.
+    link: usize,
+    /// The palindromes `c + this + c`.
+    edges: BTreeMap<char, usize>,
+    /// The index just past the first occurrence of the palindrome.
+    end: usize,
+    /// How many times this was the longest palindromic suffix of the text so far.
+    ends_here: usize,
+}
+
+/// The palindromic tree, or eertree, of a growing text: one node for every distinct palindrome in it, built online as
+/// characters are appended in amortized constant time each.
+///
+/// ```
+/// use palindrome_products::text::Eertree;
+///
+/// let tree: Eertree = "eertree".chars().collect();
+/// assert_eq!(tree.distinct_count(), 7);
+/// assert_eq!(tree.occurrences("ee"), 2);
+/// assert_eq!(tree.occurrences("rtr"), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Eertree {
+    text: Vec<char>,
+    /// The two roots, of length -1 and 0, followed by the palindromes in the order they first appeared.
+    nodes: Vec<EertreeNode>,
+    /// The node of the longest palindromic suffix of the text.
+    last: usize,
+}
+
+impl Eertree {
+    /// The tree of an empty text.
+    pub fn new() -> Eertree {
+        let root = |len| EertreeNode { len, link: 0, edges: BTreeMap::new(), end: 0, ends_here: 0 };
+        Eertree { text: Vec::new(), nodes: vec![root(-1), root(0)], last: 1 }
+    }
+
+    /// Append `c` to the text, returning whether this made a palindrome appear which was not in the text before. At
+    /// most one can.
+    pub fn push(&mut self, c: char) -> bool {
+        self.text.push(c);
+        let parent = self.suffix_extending(self.last, c);
+        if let Some(&node) = self.nodes[parent].edges.get(&c) {
+            self.nodes[node].ends_here += 1;
+            self.last = node;
+            return false;
+        }
+
+        let len = self.nodes[parent].len + 2;
+        let link = match len {
+            1 => 1,
+            _ => {
+                // the longest proper palindromic suffix of the new palindrome occurred in the text before, so it has a node
+                let node = self.suffix_extending(self.nodes[parent].link, c);
+                let link = self.nodes[node].edges.get(&c).copied();
+                debug_assert!(link.is_some(), "suffix palindrome of length {len} has no node");
+                link.unwrap_or(1)
+            }
+        };
+        self.nodes.push(EertreeNode { len, link, edges: BTreeMap::new(), end: self.text.len(), ends_here: 1 });
+        self.last = self.nodes.len() - 1;
+        self.nodes[parent].edges.insert(c, self.last);
+        true
+    }
+
+    /// The longest palindromic suffix, starting from `node` and following suffix links, which is preceded by `c`
+    /// where `c` was just appended, so that it extends into a palindrome by `c` on both ends.
+    fn suffix_extending(&self, mut node: usize, c: char) -> usize {
+        let i = self.text.len() - 1;
+        loop {
+            // the imaginary root always extends, as i - 1 - (-1) is the new character itself
+            let before = i as isize - 1 - self.nodes[node].len;
+            if before >= 0 && self.text[before as usize] == c {
+                return node;
+            }
+            node = self.nodes[node].link;
+        }
+    }
+
+    /// The number of distinct non-empty palindromes in the text.
+    pub fn distinct_count(&self) -> usize {
+        self.nodes.len() - 2
+    }
+
+    /// How many times each node occurs in the text: as the longest palindromic suffix of some prefix, or as a
+    /// palindromic suffix of a longer one. Later nodes only link to earlier ones, so one backwards pass adds up both.
+    fn occurrence_counts(&self) -> Vec<usize> {
+        let mut counts: Vec<usize> = self.nodes.iter().map(|node| node.ends_here).collect();
+        for node in (2..self.nodes.len()).rev() {
+            counts[self.nodes[node].link] += counts[node];
+        }
+        counts
+    }
+
+    /// The number of positions at which `palindrome` occurs in the text; zero if it is not a non-empty palindrome.
+    pub fn occurrences(&self, palindrome: &str) -> usize {
+        let chars: Vec<char> = palindrome.chars().collect();
+        if chars.is_empty() || !is_palindrome(&chars) {
+            return 0;
+        }
+
+        // walk out from the middle: every edge adds a character on both ends
+        let root = match chars.len() % 2 {
+            1 => 0,
+            _ => 1,
+        };
+        let node = chars[chars.len() / 2..].iter().try_fold(root, |node, c| self.nodes[node].edges.get(c).copied());
+        node.map_or(0, |node| self.occurrence_counts()[node])
+    }
+
+    /// Every distinct palindrome in the text with its number of occurrences, in the order they first appeared.
+    pub fn palindromes(&self) -> Vec<(String, usize)> {
+        let counts = self.occurrence_counts();
+        self.nodes[2..]
+            .iter()
+            .zip(&counts[2..])
+            .map(|(node, &count)| (self.text[node.end - node.len as usize..node.end].iter().collect(), count))
+            .collect()
+    }
+}
+
+impl Default for Eertree {
+    fn default() -> Eertree {
+        Eertree::new()
+    }
+}
+
+impl Extend<char> for Eertree {
+    fn extend<T: IntoIterator<Item = char>>(&mut self, chars: T) {
+        for c in chars {
+            self.push(c);
+        }
+    }
+}
+
+impl FromIterator<char> for Eertree {
+    fn from_iter<T: IntoIterator<Item = char>>(chars: T) -> Eertree {
+        let mut tree = Eertree::new();
+        tree.extend(chars);
+        tree
+    }
+}
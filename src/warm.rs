@@ -0,0 +1,108 @@
+//! A process-wide table of the palindromes up to some digit count, generated ahead of time.
+//!
+//! [`Palindrome::in_range`](crate::Palindrome::in_range) serves every range the table covers from it, and with it the
+//! palindrome-first searches and everything else which enumerates palindromes. Likewise
+//! [`Palindrome::new`](crate::Palindrome::new) looks up every value the table covers in it, and with it the
+//! product-first searches, which test their candidates that way. Services can therefore pay for the generation at
+//! startup instead of on their first request.
+
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::radix::{RadixPalindromes, MAX_DIGITS};
+
+/// The palindromes with at most `digits` digits, ascending.
+struct Table {
+    digits: u32,
+    values: Arc<[u64]>,
+}
+
+static TABLE: RwLock<Option<Table>> = RwLock::new(None);
+
+/// The digit count of [`TABLE`], zero while it is empty, so that checks need not take the lock before any warm-up.
+static DIGITS: AtomicU32 = AtomicU32::new(0);
+
+/// Generate every palindrome with at most `max_digits` digits into the shared table, unless it already holds them.
+/// Later enumerations and checks of palindromes within the table read them from it.
+///
+/// The table only grows: warming up to fewer digits than before keeps the larger table. It holds about
+/// `2 * 10^(max_digits / 2)` values of eight bytes each, so twelve digits take some 16 MB and every further two digits
+/// ten times as much. Digit counts beyond twenty are treated as twenty.
+pub fn warm_up(max_digits: u32) {
+    let digits = max_digits.min(MAX_DIGITS);
+    if warmed_up_digits() >= digits {
+        return;
+    }
+
+    // generate outside the lock, so that concurrent searches keep using the smaller table meanwhile
+    let end = 10u64.checked_pow(digits).map_or(u64::MAX, |bound| bound - 1);
+    let values: Arc<[u64]> = RadixPalindromes::new(0, end, 10).collect();
+    let mut table = TABLE.write().unwrap_or_else(PoisonError::into_inner);
+    if table.as_ref().is_none_or(|table| table.digits < digits) {
+        *table = Some(Table { digits, values });
+        DIGITS.store(digits, Ordering::Release);
+    }
+}
+
+/// Whether [`warm_up`] has generated any palindromes yet.
+pub fn is_warmed_up() -> bool {
+    warmed_up_digits() > 0
+}
+
+/// The digit count up to which the shared table holds every palindrome, zero before [`warm_up`].
+pub fn warmed_up_digits() -> u32 {
+    DIGITS.load(Ordering::Acquire)
+}
+
+/// Whether the shared table holds `value`, or `None` if it does not cover it, as before [`warm_up`].
+pub(crate) fn lookup(value: u64) -> Option<bool> {
+    let digits = DIGITS.load(Ordering::Acquire);
+    if digits == 0 || 10u64.checked_pow(digits).is_some_and(|bound| value >= bound) {
+        return None;
+    }
+    let table = TABLE.read().unwrap_or_else(PoisonError::into_inner);
+    table.as_ref().map(|table| table.values.binary_search(&value).is_ok())
+}
+
+/// The palindromes within `range` from the shared table, if it covers the whole range.
+pub(crate) fn cached(range: &RangeInclusive<u64>) -> Option<CachedPalindromes> {
+    let table = TABLE.read().unwrap_or_else(PoisonError::into_inner);
+    let table = table.as_ref()?;
+    let covered = 10u64.checked_pow(table.digits).is_none_or(|bound| *range.end() < bound);
+    if !covered {
+        return None;
+    }
+
+    let start = table.values.partition_point(|&v| v < *range.start());
+    let end = table.values.partition_point(|&v| v <= *range.end());
+    Some(CachedPalindromes { values: Arc::clone(&table.values), indices: start..end.max(start) })
+}
+
+/// Iterator over a slice of the shared table, ascending from the front and descending from the back.
+#[derive(Clone)]
+pub(crate) struct CachedPalindromes {
+    values: Arc<[u64]>,
+    indices: Range<usize>,
+}
+
+impl Iterator for CachedPalindromes {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().and_then(|i| self.values.get(i).copied())
+    }
+}
+
+impl DoubleEndedIterator for CachedPalindromes {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().and_then(|i| self.values.get(i).copied())
+    }
+}
+
+impl fmt::Debug for CachedPalindromes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the table itself is far too long to show
+        f.debug_struct("CachedPalindromes").field("indices", &self.indices).finish()
+    }
+}
@@ -0,0 +1,57 @@
+//! Certificates which let a cached search result be re-checked cheaply.
+
+use crate::{Palindrome, Palindromic};
+
+/// A claimed palindromic product together with the factor pairs which produce it.
+pub type Claim = (Palindrome, Vec<(u64, u64)>);
+
+/// The extreme palindromes claimed by a search together with their factor pairs, returned by
+/// [`Search::witness`](crate::Search::witness).
+///
+/// [`verify`](Witness::verify) re-checks every claim from scratch without trusting the types: that each value reads
+/// the same backwards, and that each pair lies in the range and multiplies to the value. It does not prove that no
+/// smaller or larger palindrome exists, which would mean repeating the search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Witness {
+    smallest: Option<Claim>,
+    largest: Option<Claim>,
+}
+
+impl Witness {
+    /// Assemble a witness, for example from a cached result.
+    pub fn new(smallest: Option<Claim>, largest: Option<Claim>) -> Witness {
+        Witness { smallest, largest }
+    }
+
+    /// The claimed smallest palindrome and its factor pairs.
+    pub fn smallest(&self) -> Option<&Claim> {
+        self.smallest.as_ref()
+    }
+
+    /// The claimed largest palindrome and its factor pairs.
+    pub fn largest(&self) -> Option<&Claim> {
+        self.largest.as_ref()
+    }
+
+    /// Whether every claim holds for factors within `min..=max`: both extremes are present or both absent, they are
+    /// ordered, each is a palindrome, and each has at least one factor pair, all of which lie in the range and
+    /// multiply to it.
+    pub fn verify(&self, min: u64, max: u64) -> bool {
+        let claim_holds = |(p, pairs): &Claim| {
+            let value = p.into_inner();
+            value.is_palindrome()
+                && !pairs.is_empty()
+                && pairs.iter().all(|&(a, b)| {
+                    (min..=max).contains(&a) && (min..=max).contains(&b) && a.checked_mul(b) == Some(value)
+                })
+        };
+
+        match (&self.smallest, &self.largest) {
+            (None, None) => true,
+            (Some(smallest), Some(largest)) => {
+                smallest.0 <= largest.0 && claim_holds(smallest) && claim_holds(largest)
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use palindrome_products::{DigitAlphabet, Palindrome, ParseError};
+
+#[test]
+/// palindromes parse from every supported alphabet
+fn test_parse_non_ascii_digits() {
+    for s in ["12321", "١٢٣٢١", "۱۲۳۲۱", "१२३२१", "１２３２１"] {
+        assert_eq!(s.parse::<Palindrome>().map(Palindrome::into_inner), Ok(12321), "{s}");
+    }
+}
+
+#[test]
+/// parsing reports why the input was rejected
+fn test_parse_errors() {
+    assert_eq!("".parse::<Palindrome>(), Err(ParseError::Empty));
+    assert_eq!("12a21".parse::<Palindrome>(), Err(ParseError::InvalidDigit('a')));
+    assert_eq!("1२1".parse::<Palindrome>(), Err(ParseError::InvalidDigit('२')));
+    let not_palindrome = Palindrome::try_new(123).expect_err("is not a palindrome");
+    assert_eq!("123".parse::<Palindrome>(), Err(ParseError::NotPalindrome(not_palindrome)));
+    assert_eq!("99999999999999999999".parse::<Palindrome>(), Err(ParseError::Overflow));
+    assert_eq!(Palindrome::parse_with("121", DigitAlphabet::Devanagari), Err(ParseError::InvalidDigit('1')));
+}
+
+#[test]
+/// palindromes format with the requested alphabet
+fn test_format_with_alphabet() {
+    let p = Palindrome::new(9009).expect("is a palindrome");
+    assert_eq!(p.to_string(), "9009");
+    assert_eq!(p.to_string_with(DigitAlphabet::ArabicIndic), "٩٠٠٩");
+    assert_eq!(p.to_string_with(DigitAlphabet::Fullwidth), "９００９");
+    for alphabet in DigitAlphabet::ALL {
+        assert_eq!(Palindrome::parse_with(&p.to_string_with(alphabet), alphabet), Ok(p));
+    }
+}
+
+#[test]
+/// large palindromes format with thousands separators
+fn test_to_grouped_string() {
+    let grouped = |v, sep| Palindrome::new(v).expect("is a palindrome").to_grouped_string(sep);
+    assert_eq!(grouped(906609, ','), "906,609");
+    assert_eq!(grouped(123454321, ','), "123,454,321");
+    assert_eq!(grouped(1234321, '.'), "1.234.321");
+    assert_eq!(grouped(99000099, '\u{202f}'), "99\u{202f}000\u{202f}099");
+    assert_eq!(grouped(121, ','), "121");
+    assert_eq!(grouped(0, ','), "0");
+}
+
+#[test]
+/// the std radix formatting traits format the inner value
+fn test_radix_formatting() {
+    let p = Palindrome::new(9009).expect("is a palindrome");
+    assert_eq!(format!("{p:x}"), "2331");
+    assert_eq!(format!("{p:#X}"), "0x2331");
+    assert_eq!(format!("{p:o}"), "21461");
+    assert_eq!(format!("{p:b}"), "10001100110001");
+    assert_eq!(format!("{p:#010x}"), "0x00002331");
+    assert_eq!(format!("{p:>8}"), "    9009");
+}
+
+#[test]
+/// radix parsing validates in base ten, or in the parsed radix
+fn test_from_str_radix() {
+    assert_eq!(Palindrome::from_str_radix("2331", 16).map(Palindrome::into_inner), Ok(9009));
+    assert_eq!(Palindrome::from_str_radix("10001100110001", 2).map(Palindrome::into_inner), Ok(9009));
+    assert_eq!(Palindrome::from_str_radix("Ff", 16), Err(Palindrome::try_new(255).unwrap_err().into()));
+    assert_eq!(Palindrome::from_str_radix("", 16), Err(ParseError::Empty));
+    assert_eq!(Palindrome::from_str_radix("12g", 16), Err(ParseError::InvalidDigit('g')));
+    assert_eq!(Palindrome::from_str_radix("1".repeat(65).as_str(), 2), Err(ParseError::Overflow));
+
+    assert_eq!(Palindrome::value_from_str_radix("1001", 2), Ok(9));
+    assert_eq!(Palindrome::value_from_str_radix("abba", 16), Ok(0xabba));
+    let Err(ParseError::NotPalindrome(e)) = Palindrome::value_from_str_radix("2331", 16) else {
+        panic!("2331 is not a hexadecimal palindrome");
+    };
+    assert_eq!((e.value(), e.reversed(), e.base()), (0x2331, 0x1332, 16));
+    assert_eq!(e.to_string(), "9009 is not a palindrome in base 16: it reads 4914 backwards");
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "arbitrary")]
+
+use std::collections::BTreeSet;
+
+use arbitrary::{Arbitrary, Unstructured};
+use palindrome_products::{Palindrome, Search};
+
+/// A tiny deterministic generator, so failures are reproducible.
+fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+fn random_bytes(seed: &mut u64, len: usize) -> Vec<u8> {
+    (0..len).map(|_| next_random(seed) as u8).collect()
+}
+
+#[test]
+/// arbitrary palindromes are valid and cover every digit count
+fn test_arbitrary_palindromes() {
+    let mut seed = 0x2545_f491_4f6c_dd1d;
+    let mut lengths = BTreeSet::new();
+    for _ in 0..2000 {
+        let bytes = random_bytes(&mut seed, 32);
+        let p = Palindrome::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(Palindrome::new(p.into_inner()), Some(p));
+        lengths.insert(p.to_string().len());
+    }
+    assert_eq!(lengths, (1..=20).collect());
+}
+
+#[test]
+/// arbitrary searches run, and their results are consistent
+fn test_arbitrary_searches() {
+    let mut seed = 0x9e37_79b9_7f4a_7c15;
+    for _ in 0..50 {
+        let bytes = random_bytes(&mut seed, 64);
+        let search = Search::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        if let Ok(result) = search.run() {
+            for p in result.smallest().into_iter().chain(result.largest()) {
+                assert!(!search.factors(p).is_empty(), "{search:?}: {p}");
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "arrow")]
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{UInt32Type, UInt64Type};
+use arrow_array::RecordBatch;
+use palindrome_products::{arrow, PalindromeProducts};
+
+/// The rows of a batch as tuples, for comparison.
+fn rows(batch: &RecordBatch) -> Vec<(String, u64, u64, u64, u32)> {
+    let extremes = batch.column(0).as_string::<i32>();
+    let palindromes = batch.column(1).as_primitive::<UInt64Type>();
+    let factors_a = batch.column(2).as_primitive::<UInt64Type>();
+    let factors_b = batch.column(3).as_primitive::<UInt64Type>();
+    let digits = batch.column(4).as_primitive::<UInt32Type>();
+    (0..batch.num_rows())
+        .map(|i| {
+            let extreme = extremes.value(i).to_string();
+            (extreme, palindromes.value(i), factors_a.value(i), factors_b.value(i), digits.value(i))
+        })
+        .collect()
+}
+
+#[test]
+/// every factor pair of both extremes becomes a row following the schema
+fn test_to_record_batch() {
+    let batch = PalindromeProducts::new(1, 9).to_record_batch().unwrap();
+    assert_eq!(batch.schema(), arrow::schema());
+    let (smallest, largest) = ("smallest".to_string(), "largest".to_string());
+    let expected = [(smallest.clone(), 1, 1, 1, 1), (largest.clone(), 9, 1, 9, 1), (largest.clone(), 9, 3, 3, 1)];
+    assert_eq!(rows(&batch), expected);
+
+    let batch = PalindromeProducts::new(100, 999).to_record_batch().unwrap();
+    assert_eq!(rows(&batch), [(smallest, 10201, 101, 101, 5), (largest, 906609, 913, 993, 6)]);
+}
+
+#[test]
+/// a range without palindromic products gives an empty batch with the same schema
+fn test_to_record_batch_empty() {
+    for (min, max) in [(15, 15), (2, 1)] {
+        let batch = PalindromeProducts::new(min, max).to_record_batch().unwrap();
+        assert_eq!(batch.num_rows(), 0);
+        assert_eq!(batch.schema(), arrow::schema());
+    }
+}
+
+#[test]
+/// the schema names and types its columns
+fn test_schema() {
+    let schema = arrow::schema();
+    let names: Vec<&str> = schema.fields().iter().map(|field| field.name().as_str()).collect();
+    assert_eq!(names, ["extreme", "palindrome", "factor_a", "factor_b", "digits"]);
+    assert!(schema.fields().iter().all(|field| !field.is_nullable()));
+}
@@ -0,0 +1,46 @@
+use palindrome_products::binary::binary_palindrome_products;
+use palindrome_products::BinaryPalindrome;
+
+#[test]
+/// test `BinaryPalindrome::new` with valid and invalid input
+fn test_binary_palindrome_new() {
+    for v in [0, 1, 0b11, 0b101, 0b1001, 0b10111101, u64::MAX] {
+        assert_eq!(BinaryPalindrome::new(v).expect("is a palindrome").into_inner(), v);
+    }
+    for v in [0b10, 0b110, 0b1011, 1 << 63] {
+        assert_eq!(BinaryPalindrome::new(v), None);
+    }
+}
+
+#[test]
+/// the constructive generator agrees with filtering every value
+fn test_binary_palindromes_in_range() {
+    let generated: Vec<u64> = BinaryPalindrome::in_range(0..=5000).map(BinaryPalindrome::into_inner).collect();
+    let filtered: Vec<u64> = (0..=5000).filter(|&v| BinaryPalindrome::new(v).is_some()).collect();
+    assert_eq!(generated, filtered);
+    assert_eq!(BinaryPalindrome::in_range(6..=6).next(), None);
+}
+
+#[test]
+/// the generator stops at the largest binary palindrome in u64
+fn test_binary_palindromes_at_upper_bound() {
+    let top: Vec<u64> = BinaryPalindrome::in_range(u64::MAX - 1..=u64::MAX).map(BinaryPalindrome::into_inner).collect();
+    assert_eq!(top, [u64::MAX]);
+}
+
+#[test]
+/// finds the smallest and largest binary palindromic products
+fn test_binary_palindrome_products() {
+    let (min, max) = binary_palindrome_products(10, 20).expect("products exist");
+    assert_eq!((min.into_inner(), max.into_inner()), (165, 255));
+    assert_eq!(binary_palindrome_products(3, 2), None);
+}
+
+#[test]
+/// binary palindromes can be generated from the top of the range
+fn test_binary_palindromes_rev() {
+    let mut generated: Vec<u64> = BinaryPalindrome::in_range(100..=5000).rev().map(BinaryPalindrome::into_inner).collect();
+    generated.reverse();
+    let filtered: Vec<u64> = (100..=5000).filter(|&v| BinaryPalindrome::new(v).is_some()).collect();
+    assert_eq!(generated, filtered);
+}
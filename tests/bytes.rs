@@ -0,0 +1,30 @@
+use palindrome_products::bytes::is_byte_palindrome;
+use palindrome_products::BytePalindrome;
+
+#[test]
+/// all eight bytes must mirror when checking the full width
+fn test_full_width_byte_palindrome() {
+    assert!(is_byte_palindrome(0, false));
+    assert!(is_byte_palindrome(0x1234_5678_7856_3412, false));
+    assert!(is_byte_palindrome(0xff00_0000_0000_00ff, false));
+    assert!(!is_byte_palindrome(0x12_3412, false));
+}
+
+#[test]
+/// leading zero bytes are ignored when checking significant bytes only
+fn test_significant_byte_palindrome() {
+    for v in [0, 0x7f, 0x12_3412, 0xabab, 0x1234_5678_7856_3412] {
+        assert!(is_byte_palindrome(v, true), "{v:#x}");
+    }
+    for v in [0x1234, 0x0100, 0x12_3456] {
+        assert!(!is_byte_palindrome(v, true), "{v:#x}");
+    }
+}
+
+#[test]
+/// test `BytePalindrome` constructors
+fn test_byte_palindrome_new() {
+    assert_eq!(BytePalindrome::new_significant(0x12_3412).map(BytePalindrome::into_inner), Some(0x12_3412));
+    assert_eq!(BytePalindrome::new(0x12_3412), None);
+    assert_eq!(BytePalindrome::new(u64::MAX).map(BytePalindrome::into_inner), Some(u64::MAX));
+}
@@ -0,0 +1,89 @@
+use std::collections::{BTreeSet, HashMap};
+
+use palindrome_products::{DigitBuf, Palindrome};
+
+/// Largest base ten palindrome representable in a `u64`.
+const LARGEST: u64 = 18446744066044764481;
+
+fn palindrome(v: u64) -> Palindrome {
+    Palindrome::new(v).expect("is a palindrome")
+}
+
+#[test]
+/// palindromes convert into their value
+fn test_into_u64() {
+    let total: u64 = [121, 9009].into_iter().map(palindrome).map(u64::from).sum();
+    assert_eq!(total, 9130);
+    let v: u64 = palindrome(906609).into();
+    assert_eq!(v, 906609);
+    assert_eq!(*palindrome(44).as_ref(), 44);
+}
+
+#[test]
+/// maps and sets keyed by palindromes can be queried by value
+fn test_borrow_as_u64() {
+    let mut factors = HashMap::new();
+    factors.insert(palindrome(9009), (91, 99));
+    assert_eq!(factors.get(&9009), Some(&(91, 99)));
+
+    let set: BTreeSet<Palindrome> = [1, 2, 3].into_iter().map(palindrome).collect();
+    assert!(set.contains(&2));
+    assert!(!set.contains(&4));
+}
+
+#[test]
+/// palindromes compare with plain integers in both directions
+fn test_mixed_comparisons() {
+    let p = palindrome(9009);
+    assert!(p == 9009);
+    assert!(9009 == p);
+    assert!(p != 9000);
+    assert!(p > 9000);
+    assert!(p < 10_000);
+    assert!(9000 < p);
+    assert!(10_000 >= p);
+}
+
+#[test]
+/// `try_new` accepts palindromes and describes rejected values
+fn test_try_new() {
+    assert_eq!(Palindrome::try_new(12321), Ok(palindrome(12321)));
+
+    let err = Palindrome::try_new(1230).expect_err("is not a palindrome");
+    assert_eq!(err.value(), 1230);
+    assert_eq!(err.reversed(), 321);
+    assert_eq!(err.to_string(), "1230 is not a palindrome: it reads 321 backwards");
+
+    let err = Palindrome::try_new(u64::MAX).expect_err("is not a palindrome");
+    assert_eq!(err.reversed(), 51615590737044764481);
+}
+
+#[test]
+/// checking with a reused digit buffer agrees with the plain constructor
+fn test_check_with_reused_buffer() {
+    let mut buf = DigitBuf::new();
+    for v in (0..20_000).chain([LARGEST, LARGEST - 1, u64::MAX]) {
+        assert_eq!(Palindrome::check_with(&mut buf, v), Palindrome::new(v), "{v}");
+    }
+}
+
+#[test]
+/// exactly the single digit palindromes are trivial
+fn test_is_trivial() {
+    assert!((0..=9).map(palindrome).all(|p| p.is_trivial()));
+    assert!([11, 101, 9009, LARGEST].map(palindrome).iter().all(|p| !p.is_trivial()));
+}
+
+#[test]
+/// palindromes sum and multiply into integers, by value and by reference
+fn test_sum_and_product() {
+    let palindromes = [121, 9009, 4].map(palindrome);
+    assert_eq!(palindromes.iter().sum::<u64>(), 9134);
+    assert_eq!(palindromes.into_iter().sum::<u128>(), 9134);
+    assert_eq!(palindromes.iter().product::<u64>(), 121 * 9009 * 4);
+    assert_eq!(palindromes.into_iter().product::<u128>(), 121 * 9009 * 4);
+
+    let large = [LARGEST, LARGEST].map(palindrome);
+    assert_eq!(large.iter().sum::<u128>(), 2 * LARGEST as u128);
+    assert_eq!(large.iter().product::<u128>(), LARGEST as u128 * LARGEST as u128);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "chrono")]
+
+use chrono::NaiveDate;
+use palindrome_products::dates::{is_palindromic_date, palindromic_dates, DateFormat};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).expect("valid date")
+}
+
+#[test]
+/// test `is_palindromic_date` under each format
+fn test_is_palindromic_date() {
+    assert!(is_palindromic_date(date(2021, 12, 2), DateFormat::YearMonthDay));
+    assert!(is_palindromic_date(date(2020, 2, 2), DateFormat::DayMonthYear));
+    assert!(is_palindromic_date(date(2021, 12, 2), DateFormat::MonthDayYear));
+    assert!(!is_palindromic_date(date(2021, 12, 2), DateFormat::DayMonthYear));
+    assert!(!is_palindromic_date(date(10000, 1, 1), DateFormat::YearMonthDay));
+}
+
+#[test]
+/// iteration agrees with checking every day of the range
+fn test_palindromic_dates_in_range() {
+    for format in DateFormat::ALL {
+        let generated: Vec<NaiveDate> = palindromic_dates(1990..=2100, format).collect();
+        let checked: Vec<NaiveDate> = date(1990, 1, 1)
+            .iter_days()
+            .take_while(|d| *d <= date(2100, 12, 31))
+            .filter(|&d| is_palindromic_date(d, format))
+            .collect();
+        assert_eq!(generated, checked, "{format:?}");
+    }
+}
+
+#[test]
+/// years with no palindromic date are skipped
+fn test_palindromic_dates_skip_years() {
+    let dates: Vec<NaiveDate> = palindromic_dates(2000..=2030, DateFormat::YearMonthDay).collect();
+    let expected = [
+        date(2001, 10, 2),
+        date(2010, 1, 2),
+        date(2011, 11, 2),
+        date(2020, 2, 2),
+        date(2021, 12, 2),
+        date(2030, 3, 2),
+    ];
+    assert_eq!(dates, expected);
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "defmt")]
+
+use palindrome_products::{
+    MergeError, NotPalindrome, Palindrome, PalindromeProducts, ParseError, SearchError, Shard, ShardResult, Strategy,
+};
+
+fn assert_format<T: defmt::Format>() {}
+
+#[test]
+/// the values, errors and results of the crate can be logged through defmt
+fn test_defmt_format() {
+    assert_format::<Palindrome>();
+    assert_format::<ParseError>();
+    assert_format::<NotPalindrome>();
+    assert_format::<MergeError>();
+    assert_format::<SearchError>();
+    assert_format::<PalindromeProducts>();
+    assert_format::<Strategy>();
+    assert_format::<Shard>();
+    assert_format::<ShardResult>();
+}
@@ -0,0 +1,134 @@
+use palindrome_products::{reverse_digits_in_base, DigitOrder, FromDigitsError, NotPalindrome, Palindrome};
+
+fn palindrome(v: u64) -> Palindrome {
+    Palindrome::new(v).expect("is a palindrome")
+}
+
+#[test]
+/// digits are listed most significant first
+fn test_to_digits_in_base() {
+    assert_eq!(palindrome(9009).to_digits_in_base(10), [9, 0, 0, 9]);
+    assert_eq!(palindrome(9009).to_digits_in_base(16), [2, 3, 3, 1]);
+    assert_eq!(palindrome(5).to_digits_in_base(2), [1, 0, 1]);
+    assert_eq!(palindrome(0).to_digits_in_base(7), [0]);
+    assert_eq!(palindrome(18446744066044764481).to_digits_in_base(256), 18446744066044764481u64.to_be_bytes());
+}
+
+#[test]
+/// the digit iterator knows its length
+fn test_digits_in_base_iterator() {
+    let mut digits = palindrome(906609).digits_in_base(10);
+    assert_eq!(digits.len(), 6);
+    assert_eq!(digits.next(), Some(9));
+    assert_eq!(digits.len(), 5);
+    assert_eq!(digits.collect::<Vec<_>>(), [0, 6, 6, 0, 9]);
+}
+
+#[test]
+#[should_panic(expected = "base 1 is not within 2..=256")]
+/// bases outside 2..=256 are rejected
+fn test_digits_in_invalid_base() {
+    palindrome(1).to_digits_in_base(1);
+}
+
+#[test]
+/// assembling from digits round-trips with the decimal digits of every palindrome
+fn test_from_digits_round_trip() {
+    for p in Palindrome::in_range(0..=100_000).chain([palindrome(18446744066044764481)]) {
+        assert_eq!(Palindrome::from_digits(&p.to_digits_in_base(10)), Ok(p));
+    }
+}
+
+#[test]
+/// invalid digit slices are rejected, checking in order digits, leading zeros, size and palindromicity
+fn test_from_digits_errors() {
+    assert_eq!(Palindrome::from_digits(&[]), Err(FromDigitsError::Empty));
+    assert_eq!(Palindrome::from_digits(&[1, 10, 1]), Err(FromDigitsError::InvalidDigit { index: 1, digit: 10 }));
+    assert_eq!(Palindrome::from_digits(&[0, 1, 0]), Err(FromDigitsError::LeadingZero));
+    assert_eq!(Palindrome::from_digits(&[0, 12]), Err(FromDigitsError::InvalidDigit { index: 1, digit: 12 }));
+    assert_eq!(Palindrome::from_digits(&[0]), Ok(palindrome(0)));
+    assert_eq!(Palindrome::from_digits(&[9; 20]), Err(FromDigitsError::Overflow));
+    assert_eq!(Palindrome::from_digits(&[1; 21]), Err(FromDigitsError::Overflow));
+
+    let err = Palindrome::from_digits(&[1, 2]).unwrap_err();
+    assert_eq!(err, FromDigitsError::NotPalindrome(Palindrome::try_new(12).unwrap_err()));
+    assert_eq!(err.to_string(), "12 is not a palindrome: it reads 21 backwards");
+    let source = std::error::Error::source(&err).and_then(|e| e.downcast_ref::<NotPalindrome>());
+    assert_eq!(source.map(NotPalindrome::value), Some(12));
+    assert_eq!(FromDigitsError::InvalidDigit { index: 3, digit: 255 }.to_string(), "invalid digit 255 at index 3");
+}
+
+#[test]
+/// the digit iterator can be consumed from both ends and keeps an exact length
+fn test_digits_in_base_reversed() {
+    let mut digits = palindrome(9009).digits_in_base(16);
+    assert_eq!(digits.next_back(), Some(1));
+    assert_eq!(digits.len(), 3);
+    assert_eq!(digits.next(), Some(2));
+    assert_eq!(digits.next_back(), Some(3));
+    assert_eq!(digits.len(), 1);
+    assert_eq!(digits.next(), Some(3));
+    assert_eq!((digits.len(), digits.next(), digits.next_back()), (0, None, None));
+
+    let largest = palindrome(18446744066044764481);
+    for base in [2, 10, 16, 256] {
+        let mut forward = largest.to_digits_in_base(base);
+        forward.reverse();
+        assert_eq!(largest.digits_in_base(base).rev().collect::<Vec<_>>(), forward, "base {base}");
+        assert_eq!(largest.to_digits_in_base_ordered(base, DigitOrder::LeastSignificantFirst), forward);
+    }
+    assert_eq!(palindrome(9009).to_digits_in_base_ordered(16, DigitOrder::MostSignificantFirst), [2, 3, 3, 1]);
+    assert_eq!(palindrome(9009).to_digits_in_base_ordered(16, DigitOrder::LeastSignificantFirst), [1, 3, 3, 2]);
+    assert_eq!(palindrome(0).to_digits_in_base_ordered(3, DigitOrder::LeastSignificantFirst), [0]);
+}
+
+#[test]
+/// digits in either order round-trip, and validation follows the order
+fn test_from_digits_ordered() {
+    use DigitOrder::{LeastSignificantFirst, MostSignificantFirst};
+
+    for p in [0, 7, 121, 9009, 18446744066044764481].map(palindrome) {
+        for order in [MostSignificantFirst, LeastSignificantFirst] {
+            assert_eq!(Palindrome::from_digits_ordered(&p.to_digits_in_base_ordered(10, order), order), Ok(p));
+        }
+    }
+    assert_eq!(Palindrome::from_digits_ordered(&[1, 0], LeastSignificantFirst), Err(FromDigitsError::LeadingZero));
+    assert_eq!(Palindrome::from_digits_ordered(&[0, 1], MostSignificantFirst), Err(FromDigitsError::LeadingZero));
+    let err = Palindrome::from_digits_ordered(&[1, 2], LeastSignificantFirst).unwrap_err();
+    assert_eq!(err.to_string(), "21 is not a palindrome: it reads 12 backwards");
+    assert_eq!(
+        Palindrome::from_digits_ordered(&[1, 10], LeastSignificantFirst),
+        Err(FromDigitsError::InvalidDigit { index: 1, digit: 10 })
+    );
+    assert_eq!(Palindrome::from_digits_ordered(&[], LeastSignificantFirst), Err(FromDigitsError::Empty));
+    assert_eq!(DigitOrder::default(), MostSignificantFirst);
+}
+
+#[test]
+/// reversing digits in a base drops trailing zeros and reports overflow
+fn test_reverse_digits_in_base() {
+    assert_eq!(reverse_digits_in_base(1234, 10), Some(4321));
+    assert_eq!(reverse_digits_in_base(120, 10), Some(21));
+    assert_eq!(reverse_digits_in_base(0, 10), Some(0));
+    assert_eq!(reverse_digits_in_base(0b1101, 2), Some(0b1011));
+    assert_eq!(reverse_digits_in_base(0x12f, 16), Some(0xf21));
+    assert_eq!(reverse_digits_in_base(258, 256), Some(513));
+    assert_eq!(reverse_digits_in_base(u64::MAX, 10), None);
+    assert_eq!(reverse_digits_in_base(u64::MAX, 2), Some(u64::MAX));
+    assert_eq!(reverse_digits_in_base(10_000_000_000_000_000_009, 10), None);
+    assert_eq!(reverse_digits_in_base(10_000_000_000_000_000_001, 10), Some(10_000_000_000_000_000_001));
+}
+
+#[test]
+#[should_panic(expected = "base 257 is not within 2..=256")]
+/// reversing in a base outside 2..=256 is rejected
+fn test_reverse_digits_in_invalid_base() {
+    reverse_digits_in_base(1, 257);
+}
+
+#[test]
+#[should_panic(expected = "base 1 is not within 2..=256")]
+/// there are no digits to reverse in base one
+fn test_reverse_digits_in_base_one() {
+    reverse_digits_in_base(10, 1);
+}
@@ -0,0 +1,86 @@
+use palindrome_products::{is_almost_palindrome, nearest_palindrome, palindrome_distance, Palindrome};
+
+const LARGEST: u64 = 18446744066044764481;
+
+/// Digit replacements between two numbers of the same length.
+fn replacements(a: u64, b: u64) -> u32 {
+    a.to_string().bytes().zip(b.to_string().bytes()).filter(|(x, y)| x != y).count() as u32
+}
+
+#[test]
+/// the distance and nearest palindrome agree with brute force over the palindromes of the same length
+fn test_brute_force() {
+    for n in 0..10_000u64 {
+        let len = n.to_string().len() as u32;
+        let lowest = if len == 1 { 0 } else { 10u64.pow(len - 1) };
+        let same_length = Palindrome::in_range(lowest..=10u64.pow(len) - 1).map(u64::from);
+        let (distance, _, nearest) = same_length.map(|p| (replacements(n, p), n.abs_diff(p), p)).min().unwrap();
+        assert_eq!(palindrome_distance(n), distance, "{n}");
+        assert_eq!(nearest_palindrome(n), nearest, "{n}");
+    }
+}
+
+#[test]
+/// palindromes are their own nearest
+fn test_palindromes() {
+    for p in [0, 7, 11, 12321, 9009, LARGEST] {
+        assert_eq!(palindrome_distance(p), 0);
+        assert_eq!(nearest_palindrome(p), p);
+    }
+}
+
+#[test]
+/// examples, including twenty digit numbers whose mirrored half would overflow
+fn test_examples() {
+    assert_eq!(palindrome_distance(1234), 2);
+    assert_eq!(nearest_palindrome(1234), 1221);
+    assert_eq!(nearest_palindrome(19), 11);
+    assert_eq!(nearest_palindrome(90), 99);
+    assert_eq!(nearest_palindrome(10), 11);
+
+    // mirroring the leading half gives 18446744077044764481, which is too large, so the last digit of the leading
+    // half is lowered instead
+    assert_eq!(palindrome_distance(u64::MAX), 10);
+    let nearest = nearest_palindrome(u64::MAX);
+    assert_eq!(replacements(u64::MAX, nearest.into_inner()), 10);
+    assert_eq!(nearest, 18446744033044764481);
+}
+
+/// The fewest removals and replacements which make `digits` a palindrome, by exhaustive recursion.
+fn fewest_edits(digits: &[u8]) -> u32 {
+    match digits {
+        [] | [_] => 0,
+        [first, inner @ .., last] if first == last => fewest_edits(inner),
+        [_, rest @ ..] => {
+            let without_last = &digits[..digits.len() - 1];
+            1 + fewest_edits(rest).min(fewest_edits(without_last)).min(fewest_edits(&rest[..rest.len() - 1]))
+        }
+    }
+}
+
+#[test]
+/// almost palindromes agree with exhaustive recursion for every budget
+fn test_almost_palindrome_brute_force() {
+    for n in (0..5_000u64).chain([1234567, 9876543210, 12345678987654321]) {
+        let edits = fewest_edits(n.to_string().as_bytes());
+        for k in 0..4 {
+            assert_eq!(is_almost_palindrome(n, k), edits <= k, "{n} within {k}");
+        }
+    }
+}
+
+#[test]
+/// removals and replacements each count as one edit, and never more than replacing alone
+fn test_almost_palindrome_examples() {
+    assert!(is_almost_palindrome(12321, 0));
+    assert!(!is_almost_palindrome(12341, 0));
+    assert!(is_almost_palindrome(12341, 1));
+    assert!(!is_almost_palindrome(4321, 1));
+    assert!(is_almost_palindrome(4321, 2));
+    assert!(is_almost_palindrome(0, 0));
+
+    for n in [0, 19, 1234, 98765, LARGEST + 1, u64::MAX] {
+        assert!(is_almost_palindrome(n, palindrome_distance(n)), "{n}");
+    }
+    assert!(!is_almost_palindrome(u64::MAX, 5));
+}
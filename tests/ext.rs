@@ -0,0 +1,37 @@
+use palindrome_products::{FactorPairsExt, Palindrome, PalindromeExt};
+
+#[test]
+/// the adaptors keep exactly the palindromic values, in order
+fn test_palindrome_adaptors() {
+    let values = [10, 11, 12, 121, 123, 0, 9009, 9010];
+    let palindromes: Vec<Palindrome> = values.into_iter().palindromes().collect();
+    assert_eq!(palindromes.iter().map(|p| p.into_inner()).collect::<Vec<_>>(), [11, 121, 0, 9009]);
+    let filtered: Vec<u64> = values.into_iter().filter_palindromic().collect();
+    assert_eq!(filtered, [11, 121, 0, 9009]);
+}
+
+#[test]
+/// the adaptors agree with each other over a range
+fn test_palindrome_adaptors_agree() {
+    let palindromes = (0..5000).palindromes().map(Palindrome::into_inner);
+    assert!(palindromes.eq((0..5000).filter_palindromic()));
+    assert_eq!((0..1000).filter_palindromic().count(), 109);
+}
+
+#[test]
+/// factor pairs yield their palindromic products, skipping overflowing pairs
+fn test_pair_palindrome_products() {
+    let pairs = [(3, 4), (11, 11), (u64::MAX, 2), (1, u64::MAX), (7, 0), (913, 993)];
+    let products: Vec<(u64, (u64, u64))> =
+        pairs.into_iter().palindrome_products().map(|(p, pair)| (p.into_inner(), pair)).collect();
+    assert_eq!(products, [(121, (11, 11)), (0, (7, 0)), (906609, (913, 993))]);
+}
+
+#[test]
+/// the products of every pair in a range contain the extreme palindromic products
+fn test_pair_palindrome_products_extremes() {
+    let pairs = (10..=99).flat_map(|a| (a..=99).map(move |b| (a, b)));
+    let products: Vec<Palindrome> = pairs.palindrome_products().map(|(p, _)| p).collect();
+    assert_eq!(products.iter().min().map(|p| p.into_inner()), Some(121));
+    assert_eq!(products.iter().max().map(|p| p.into_inner()), Some(9009));
+}
@@ -0,0 +1,82 @@
+use palindrome_products::{factor_pairs, palindrome_product_map, Palindrome};
+
+fn palindrome(v: u64) -> Palindrome {
+    Palindrome::new(v).expect("is a palindrome")
+}
+
+#[test]
+/// factor pairs are normalized, unique and sorted
+fn test_factor_pairs_are_ordered() {
+    assert_eq!(palindrome(9).factors(1, 9), [(1, 9), (3, 3)]);
+    assert_eq!(palindrome(121).factors(1, 121), [(1, 121), (11, 11)]);
+    assert_eq!(palindrome(252).factors(1, 99), [(3, 84), (4, 63), (6, 42), (7, 36), (9, 28), (12, 21), (14, 18)]);
+}
+
+#[test]
+/// only factors within the range are reported
+fn test_factor_pairs_within_range() {
+    assert_eq!(palindrome(9009).factors(10, 99), [(91, 99)]);
+    assert_eq!(palindrome(906609).factors(100, 999), [(913, 993)]);
+    assert_eq!(palindrome(9009).factors(100, 999), []);
+    assert_eq!(palindrome(9).factors(2, 1), []);
+}
+
+#[test]
+/// the free function finds the same pairs for palindromes, and pairs of other values too
+fn test_factor_pairs_of_any_value() {
+    for v in [0, 9, 121, 252, 9009] {
+        assert_eq!(factor_pairs(v, 0, 99).collect::<Vec<_>>(), palindrome(v).factors(0, 99), "{v}");
+    }
+    assert_eq!(factor_pairs(9, 1, 9).collect::<Vec<_>>(), [(1, 9), (3, 3)]);
+    assert_eq!(factor_pairs(100, 10, 99).collect::<Vec<_>>(), [(10, 10)]);
+    assert_eq!(factor_pairs(1000, 10, 99).collect::<Vec<_>>(), [(20, 50), (25, 40)]);
+    assert_eq!(factor_pairs(97, 2, 96).count(), 0);
+}
+
+#[test]
+/// zero is the product of zero with every factor in the range
+fn test_factor_pairs_of_zero() {
+    assert_eq!(palindrome(0).factors(0, 3), [(0, 0), (0, 1), (0, 2), (0, 3)]);
+    assert_eq!(palindrome(0).factors(1, 3), []);
+}
+
+#[test]
+/// large values do not overflow while searching factors
+fn test_factor_pairs_near_u64_max() {
+    let largest = palindrome(18446744066044764481);
+    assert!(largest.factors(u64::MAX - 1, u64::MAX).is_empty());
+    assert_eq!(largest.factors(1, 1), []);
+}
+
+#[test]
+/// the product map associates every palindromic product with all of its factor pairs
+fn test_palindrome_product_map() {
+    let map = palindrome_product_map(1, 9);
+    let entries: Vec<(u64, Vec<(u64, u64)>)> = map.iter().map(|(p, pairs)| (p.into_inner(), pairs.clone())).collect();
+    assert_eq!(
+        entries,
+        [
+            (1, vec![(1, 1)]),
+            (2, vec![(1, 2)]),
+            (3, vec![(1, 3)]),
+            (4, vec![(1, 4), (2, 2)]),
+            (5, vec![(1, 5)]),
+            (6, vec![(1, 6), (2, 3)]),
+            (7, vec![(1, 7)]),
+            (8, vec![(1, 8), (2, 4)]),
+            (9, vec![(1, 9), (3, 3)]),
+        ]
+    );
+}
+
+#[test]
+/// the product map agrees with querying each palindrome's factors
+fn test_palindrome_product_map_matches_factors() {
+    let map = palindrome_product_map(10, 99);
+    assert_eq!(map.keys().next().map(|p| p.into_inner()), Some(121));
+    assert_eq!(map.keys().last().map(|p| p.into_inner()), Some(9009));
+    for (p, pairs) in &map {
+        assert_eq!(&p.factors(10, 99), pairs);
+    }
+    assert!(palindrome_product_map(15, 15).is_empty());
+}
@@ -0,0 +1,88 @@
+use palindrome_products::Palindrome;
+
+/// Largest base ten palindrome representable in a `u64`.
+const LARGEST: u64 = 18446744066044764481;
+
+fn brute_force(start: u64) -> impl Iterator<Item = u64> {
+    (start..).filter(|&v| Palindrome::new(v).is_some())
+}
+
+#[test]
+/// successive palindromes match filtering every integer
+fn test_iter_from_matches_brute_force() {
+    for start in [0, 7, 10, 98, 100, 989, 1000, 99999, 123456] {
+        let generated: Vec<u64> = Palindrome::iter_from(start).take(50).map(Palindrome::into_inner).collect();
+        let expected: Vec<u64> = brute_force(start).take(50).collect();
+        assert_eq!(generated, expected, "starting from {start}");
+    }
+}
+
+#[test]
+/// a palindrome start is included
+fn test_iter_from_includes_start() {
+    assert_eq!(Palindrome::iter_from(9009).next().map(Palindrome::into_inner), Some(9009));
+}
+
+#[test]
+/// the iterator ends at the largest palindrome in u64
+fn test_iter_from_ends_at_u64_max() {
+    let tail: Vec<u64> = Palindrome::iter_from(LARGEST).map(Palindrome::into_inner).collect();
+    assert_eq!(tail, [LARGEST]);
+    assert_eq!(Palindrome::iter_from(LARGEST + 1).next(), None);
+}
+
+#[test]
+/// palindromes in a range match filtering, from either end
+fn test_in_range_double_ended() {
+    for (start, end) in [(0, 0), (0, 1000), (95, 105), (1000, 9999), (12, 21), (123, 130), (5000, 4000)] {
+        let expected: Vec<u64> = (start..=end).filter(|&v| Palindrome::new(v).is_some()).collect();
+        let forward: Vec<u64> = Palindrome::in_range(start..=end).map(Palindrome::into_inner).collect();
+        let mut backward: Vec<u64> = Palindrome::in_range(start..=end).rev().map(Palindrome::into_inner).collect();
+        backward.reverse();
+        assert_eq!(forward, expected, "{start}..={end}");
+        assert_eq!(backward, expected, "{start}..={end}");
+    }
+}
+
+#[test]
+/// both ends can be consumed without overlapping
+fn test_in_range_meets_in_the_middle() {
+    let mut palindromes = Palindrome::in_range(100..=200).map(Palindrome::into_inner);
+    assert_eq!(palindromes.next_back(), Some(191));
+    assert_eq!(palindromes.next(), Some(101));
+    let rest: Vec<u64> = palindromes.by_ref().collect();
+    assert_eq!(rest, [111, 121, 131, 141, 151, 161, 171, 181]);
+    assert_eq!(palindromes.next_back(), None);
+}
+
+#[test]
+/// the largest palindrome below a bound is found lazily from the top
+fn test_in_range_rev_near_u64_max() {
+    assert_eq!(Palindrome::in_range(0..=u64::MAX).next_back().map(Palindrome::into_inner), Some(LARGEST));
+    let below: Vec<u64> = Palindrome::in_range(0..=999_999_999_999).rev().take(2).map(Palindrome::into_inner).collect();
+    assert_eq!(below, [999_999_999_999, 999_998_899_999]);
+}
+
+#[test]
+/// every palindrome starts from zero and ends at the largest in u64
+fn test_all_palindromes() {
+    let first: Vec<u64> = Palindrome::all().take(13).map(Palindrome::into_inner).collect();
+    assert_eq!(first, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 11, 22, 33]);
+    assert_eq!(Palindrome::all().next_back().map(Palindrome::into_inner), Some(LARGEST));
+    assert_eq!(Palindrome::all().take_while(|p| p.into_inner() < 1_000_000).count(), 1999);
+}
+
+#[test]
+/// the k largest palindromes below a bound agree with brute force and exclude the bound itself
+fn test_k_largest_below() {
+    for n in [0, 1, 10, 12, 100, 1001, 5000] {
+        for k in [0, 1, 3, 20] {
+            let expected: Vec<u64> = (0..n).rev().filter(|v| Palindrome::new(*v).is_some()).take(k).collect();
+            let largest: Vec<u64> = Palindrome::k_largest_below(n, k).into_iter().map(Palindrome::into_inner).collect();
+            assert_eq!(largest, expected, "{k} below {n}");
+        }
+    }
+    let top: Vec<u64> = Palindrome::k_largest_below(u64::MAX, 2).into_iter().map(Palindrome::into_inner).collect();
+    assert_eq!(top, [LARGEST, 18446744055044764481]);
+    assert_eq!(Palindrome::k_largest_below(LARGEST, 1)[0], 18446744055044764481);
+}
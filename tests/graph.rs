@@ -0,0 +1,61 @@
+use palindrome_products::{NodeKind, PruneReason, Search, SearchGraph, Strategy};
+
+fn graph(search: Search) -> SearchGraph {
+    let mut graph = SearchGraph::new();
+    search.run_observed(&mut graph).expect("search runs");
+    graph
+}
+
+#[test]
+/// the graph records the traversal path, found palindromes and pruned rows
+fn test_search_graph() {
+    let graph = graph(Search::new(10, 12));
+    let nodes: Vec<(u64, NodeKind)> = graph.nodes().iter().map(|node| (node.product(), node.kind().clone())).collect();
+    let bound = NodeKind::Pruned(PruneReason::Bound);
+    assert_eq!(
+        nodes,
+        [
+            (100, NodeKind::Candidate),
+            (110, NodeKind::Candidate),
+            (120, NodeKind::Candidate),
+            (121, NodeKind::Found(vec![(11, 11)])),
+            (144, bound.clone()),
+            (144, NodeKind::Candidate),
+            (132, NodeKind::Candidate),
+            (120, NodeKind::Candidate),
+            (121, NodeKind::Found(vec![(11, 11)])),
+            (100, bound),
+        ]
+    );
+    assert_eq!(graph.edges(), [(0, 1), (1, 2), (2, 3), (3, 4), (3, 5), (5, 6), (6, 7), (7, 8), (8, 9)]);
+}
+
+#[test]
+/// the DOT export declares every node and edge
+fn test_search_graph_dot() {
+    let dot = graph(Search::new(10, 12)).to_dot();
+    assert!(dot.starts_with("digraph search {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("    n0 [label=\"100\"];\n"));
+    assert!(dot.contains("    n3 [label=\"121\\n11 × 11\", style=filled, fillcolor=palegreen];\n"));
+    assert!(dot.contains("    n4 [label=\"144\\nbound\", style=dashed];\n"));
+    assert!(dot.contains("    n3 -> n4 [style=dashed];\n"));
+    assert!(dot.contains("    n3 -> n5;\n"));
+    assert_eq!(dot.matches("->").count(), 9);
+}
+
+#[test]
+/// the JSON export lists nodes with their kinds and edges as pairs
+fn test_search_graph_json() {
+    let json = graph(Search::new(10, 11).strategy(Strategy::PalindromeFirst)).to_json();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"nodes":[{"id":0,"product":101,"kind":"candidate"},{"id":1,"product":101,"kind":"pruned","reason":"no-factors"},"#,
+            r#"{"id":2,"product":111,"kind":"candidate"},{"id":3,"product":111,"kind":"pruned","reason":"no-factors"},"#,
+            r#"{"id":4,"product":121,"kind":"found","factors":[[11,11]]},"#,
+            r#"{"id":5,"product":121,"kind":"found","factors":[[11,11]]}],"#,
+            r#""edges":[[0,1],[0,2],[2,3],[2,4],[4,5]]}"#
+        )
+    );
+}
@@ -0,0 +1,85 @@
+use palindrome_products::{extend_max, PalindromeProducts, SortedProducts};
+
+/// A tiny deterministic generator, so the interleavings are reproducible.
+fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+#[test]
+/// arbitrary interleavings of `next` and `next_back` keep the iterator consistent
+fn test_sorted_products_invariants_under_interleaving() {
+    let mut seed = 0x9e37_79b9_7f4a_7c15;
+    for _ in 0..50 {
+        let min = next_random(&mut seed) % 20 + 1;
+        let max = min + next_random(&mut seed) % 20;
+        let mut products = SortedProducts::new(min, max);
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        loop {
+            products.debug_check_invariants();
+            let from_front = next_random(&mut seed) & 1 == 0;
+            match if from_front { products.next() } else { products.next_back() } {
+                Some(v) if from_front => front.push(v),
+                Some(v) => back.push(v),
+                None => break,
+            }
+        }
+
+        let mut expected: Vec<u64> = (min..=max).flat_map(|a| (a..=max).map(move |b| a * b)).collect();
+        expected.sort();
+        expected.dedup();
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, expected, "{min}..={max}");
+    }
+}
+
+#[test]
+/// search states satisfy their invariants after extension
+fn test_palindrome_products_invariants() {
+    let mut state = PalindromeProducts::new(10, 20);
+    state.debug_check_invariants();
+    extend_max(&mut state, 99);
+    state.debug_check_invariants();
+    PalindromeProducts::new(15, 15).debug_check_invariants();
+}
+
+#[test]
+/// a range containing zero yields zero exactly once from either end
+fn test_sorted_products_with_zero() {
+    assert_eq!(SortedProducts::new(0, 3).collect::<Vec<_>>(), [0, 1, 2, 3, 4, 6, 9]);
+    assert_eq!(SortedProducts::new(0, 3).rev().collect::<Vec<_>>(), [9, 6, 4, 3, 2, 1, 0]);
+    assert_eq!(SortedProducts::new(0, 0).collect::<Vec<_>>(), [0]);
+}
+
+#[test]
+/// products which would overflow a `u64` are skipped rather than wrapping or panicking
+fn test_sorted_products_near_overflow() {
+    let (min, max) = (u32::MAX as u64 - 3, u32::MAX as u64 + 3);
+    let mut expected: Vec<u64> =
+        (min..=max).flat_map(|a| (a..=max).filter_map(move |b| a.checked_mul(b))).collect();
+    expected.sort();
+    expected.dedup();
+
+    let mut products = SortedProducts::new(min, max);
+    products.debug_check_invariants();
+    assert_eq!(products.clone().collect::<Vec<_>>(), expected);
+    let mut reversed: Vec<u64> = products.by_ref().rev().collect();
+    reversed.reverse();
+    assert_eq!(reversed, expected);
+    assert_eq!(SortedProducts::new(u64::MAX - 3, u64::MAX).next(), None);
+}
+
+#[test]
+/// a wide range only creates the rows it reaches, so both ends start immediately
+fn test_sorted_products_wide_range() {
+    let max = 100_000_000;
+    assert_eq!(SortedProducts::new(1, max).take(5).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    assert_eq!(
+        SortedProducts::new(1, max).rev().take(3).collect::<Vec<_>>(),
+        [max * max, max * (max - 1), (max - 1) * (max - 1)]
+    );
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "serde_json")]
+
+use palindrome_products::{PalindromeProducts, Shard, ShardJsonError, ShardResult};
+use serde_json::json;
+
+#[test]
+/// the report follows the documented schema
+fn test_to_json_schema() {
+    let report = PalindromeProducts::new(10, 99).to_json();
+    assert_eq!(
+        report,
+        json!({
+            "schema_version": 1,
+            "min": 10,
+            "max": 99,
+            "smallest": { "value": 121, "factors": [[11, 11]] },
+            "largest": { "value": 9009, "factors": [[91, 99]] },
+            "metrics": { "factors": 90, "factor_pairs": 4095 },
+        })
+    );
+}
+
+#[test]
+/// missing palindromes and empty ranges are reported as null and zero
+fn test_to_json_without_palindromes() {
+    let report = PalindromeProducts::new(15, 15).to_json();
+    assert_eq!(report["smallest"], json!(null));
+    assert_eq!(report["largest"], json!(null));
+    assert_eq!(report["metrics"], json!({ "factors": 1, "factor_pairs": 1 }));
+
+    let report = PalindromeProducts::new(10, 2).to_json();
+    assert_eq!(report["metrics"], json!({ "factors": 0, "factor_pairs": 0 }));
+}
+
+#[test]
+/// shard results survive a round trip through JSON
+fn test_shard_result_json_round_trip() {
+    let result = Shard::new(10, 10, 54).search();
+    let value = result.to_json();
+    assert_eq!(
+        value,
+        json!({ "schema_version": 1, "shard": { "min": 10, "from": 10, "to": 54 }, "smallest": 121, "largest": 2332 })
+    );
+    assert_eq!(ShardResult::from_json(&value), Ok(result));
+
+    let empty = Shard::new(15, 15, 15).search();
+    assert_eq!(empty.to_json()["smallest"], json!(null));
+    assert_eq!(ShardResult::from_json(&empty.to_json()), Ok(empty));
+}
+
+#[test]
+/// malformed shard results are rejected with the reason
+fn test_shard_result_from_json_errors() {
+    let shard = json!({ "min": 10, "from": 10, "to": 99 });
+    let value = json!({ "schema_version": 2, "shard": shard, "smallest": null, "largest": null });
+    assert_eq!(ShardResult::from_json(&value), Err(ShardJsonError::UnsupportedVersion(2)));
+    let value = json!({ "schema_version": 1, "shard": { "min": 10, "to": 99 }, "smallest": null, "largest": null });
+    assert_eq!(ShardResult::from_json(&value), Err(ShardJsonError::InvalidField("from")));
+    let value = json!({ "schema_version": 1, "shard": shard, "smallest": "121", "largest": null });
+    assert_eq!(ShardResult::from_json(&value), Err(ShardJsonError::InvalidField("smallest")));
+    let value = json!({ "schema_version": 1, "shard": shard, "smallest": 121, "largest": 9000 });
+    let Err(ShardJsonError::NotPalindrome(e)) = ShardResult::from_json(&value) else {
+        panic!("expected a palindrome error");
+    };
+    assert_eq!(e.value(), 9000);
+}
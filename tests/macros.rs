@@ -0,0 +1,36 @@
+#![cfg(feature = "macros")]
+
+use palindrome_products::{palindromes, Palindrome};
+
+static SMALL: &[Palindrome] = palindromes!(1..=10_000);
+
+#[test]
+/// the table holds every palindrome of the range, ascending
+fn test_palindromes_table() {
+    assert_eq!(SMALL.len(), 198);
+    assert_eq!(SMALL, Palindrome::in_range(1..=10_000).collect::<Vec<_>>());
+    assert_eq!(palindromes!(0..=0), [Palindrome::new(0).unwrap()]);
+}
+
+#[test]
+/// exclusive and empty ranges, and ranges near the top of a `u64`
+fn test_palindromes_bounds() {
+    assert_eq!(palindromes!(100..1000), Palindrome::in_range(100..=999).collect::<Vec<_>>());
+    assert_eq!(palindromes!(121..122).len(), 1);
+    assert!(palindromes!(12..22).is_empty());
+    assert!(palindromes!(0..0).is_empty());
+    assert!(palindromes!(23..=32).is_empty());
+    let top = palindromes!(18446744066044764481..=18446744073709551615);
+    assert_eq!(top.iter().map(|p| p.into_inner()).collect::<Vec<_>>(), [18446744066044764481]);
+}
+
+#[test]
+/// constant evaluation accepts exactly the palindromes
+fn test_new_const() {
+    const P: Option<Palindrome> = Palindrome::new_const(906609);
+    const Q: Option<Palindrome> = Palindrome::new_const(906608);
+    assert_eq!(P, Palindrome::new(906609));
+    assert_eq!(Q, None);
+    assert_eq!(Palindrome::new_const(u64::MAX), None);
+    assert_eq!(Palindrome::new_const(0), Palindrome::new(0));
+}
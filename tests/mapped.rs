@@ -0,0 +1,82 @@
+#![cfg(feature = "mmap")]
+
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use palindrome_products::{MappedPalindromeSet, Palindrome, PalindromeSet};
+
+/// A file in the temporary directory, removed when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str, bytes: &[u8]) -> TempFile {
+        let path = std::env::temp_dir().join(format!("palindrome-mapped-{name}-{}.bin", std::process::id()));
+        std::fs::write(&path, bytes).expect("write table");
+        TempFile(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn table(set: &PalindromeSet) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    set.write_to(&mut bytes).expect("write to memory");
+    bytes
+}
+
+#[test]
+/// a mapped table answers like the set it was saved from
+fn test_mapped_table() {
+    let mut set = PalindromeSet::below(100_000);
+    set.insert(Palindrome::new(18446744066044764481).expect("is a palindrome"));
+    let file = TempFile::new("round-trip", &table(&set));
+    let mapped = MappedPalindromeSet::open(&file.0).expect("valid table");
+
+    assert_eq!(mapped.len(), set.len());
+    assert!(mapped.iter().eq(set.iter()));
+    assert!(mapped.iter().rev().eq(set.iter().rev()));
+    assert_eq!(mapped.to_set(), set);
+    for value in (0..120_000).step_by(7).chain([18446744066044764481]) {
+        if let Some(p) = Palindrome::new(value) {
+            assert_eq!(mapped.contains(p), set.contains(p), "{value}");
+        }
+    }
+    for (lo, hi) in [(0, u64::MAX), (7, 9009), (8, 120), (122, 1000), (9009, 7), (99_999, u64::MAX)] {
+        assert_eq!(mapped.count_between(lo, hi), set.count_between(lo, hi), "{lo}..={hi}");
+        assert!(mapped.iter_range(lo..=hi).eq(set.iter_range(lo..=hi)), "{lo}..={hi}");
+    }
+    assert!(mapped.iter_range(10_000..).rev().eq(set.iter_range(10_000..).rev()));
+
+    let empty = TempFile::new("empty", &table(&PalindromeSet::new()));
+    let empty = MappedPalindromeSet::open(&empty.0).expect("valid table");
+    assert!(empty.is_empty());
+    assert_eq!(empty.iter().count(), 0);
+}
+
+#[test]
+/// malformed tables are rejected as invalid data, as when reading them
+fn test_mapped_table_rejects_malformed_input() {
+    let bytes = table(&PalindromeSet::below(1000));
+    let mut bad_magic = bytes.clone();
+    bad_magic[0] = b'X';
+    let mut unsorted = bytes.clone();
+    let last = unsorted.len() - 4;
+    unsorted[last..].copy_from_slice(&0u32.to_le_bytes());
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+
+    for (name, input) in [("magic", bad_magic), ("unsorted", unsorted), ("trailing", trailing)] {
+        let file = TempFile::new(name, &input);
+        let err = MappedPalindromeSet::open(&file.0).expect_err("malformed table");
+        assert_eq!(err.kind(), ErrorKind::InvalidData, "{name}");
+    }
+    let file = TempFile::new("truncated", &bytes[..bytes.len() - 1]);
+    let err = MappedPalindromeSet::open(&file.0).expect_err("truncated table");
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    let file = TempFile::new("header", &bytes[..10]);
+    assert_eq!(MappedPalindromeSet::open(&file.0).expect_err("short header").kind(), ErrorKind::InvalidData);
+}
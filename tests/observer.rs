@@ -0,0 +1,134 @@
+use std::ops::ControlFlow;
+use std::time::{Duration, Instant};
+
+use palindrome_products::{Palindrome, PruneReason, Search, SearchError, SearchObserver, Strategy};
+
+/// Records every event.
+#[derive(Default)]
+struct Recorder {
+    candidates: Vec<u64>,
+    pruned: Vec<(u64, PruneReason)>,
+    found: Vec<(u64, Vec<(u64, u64)>)>,
+}
+
+impl SearchObserver for Recorder {
+    fn on_candidate(&mut self, product: u64) -> ControlFlow<()> {
+        self.candidates.push(product);
+        ControlFlow::Continue(())
+    }
+
+    fn on_pruned(&mut self, product: u64, reason: PruneReason) {
+        self.pruned.push((product, reason));
+    }
+
+    fn on_palindrome_found(&mut self, p: Palindrome, factors: &[(u64, u64)]) {
+        self.found.push((p.into_inner(), factors.to_vec()));
+    }
+}
+
+/// Stops after a fixed number of candidates.
+struct Budget(usize);
+
+impl SearchObserver for Budget {
+    fn on_candidate(&mut self, _product: u64) -> ControlFlow<()> {
+        match self.0.checked_sub(1) {
+            Some(left) => {
+                self.0 = left;
+                ControlFlow::Continue(())
+            }
+            None => ControlFlow::Break(()),
+        }
+    }
+}
+
+/// Stops once a point in time has passed.
+struct Deadline(Instant);
+
+impl SearchObserver for Deadline {
+    fn on_candidate(&mut self, _product: u64) -> ControlFlow<()> {
+        self.on_tick()
+    }
+
+    fn on_tick(&mut self) -> ControlFlow<()> {
+        match Instant::now() >= self.0 {
+            true => ControlFlow::Break(()),
+            false => ControlFlow::Continue(()),
+        }
+    }
+}
+
+#[test]
+/// observed searches give the same answer as plain ones
+fn test_observed_matches_run() {
+    for (min, max) in [(1, 9), (10, 99), (100, 999), (0, 12), (15, 15), (2, 1)] {
+        for strategy in Strategy::ALL {
+            let searches = [
+                Search::new(min, max).strategy(strategy),
+                Search::new(min, max).strategy(strategy).step(3),
+                Search::new(min, max).strategy(strategy).palindromic_factors(true),
+            ];
+            for search in searches {
+                assert_eq!(search.run_observed(&mut ()), search.run(), "{search:?}");
+            }
+        }
+    }
+}
+
+#[test]
+/// the last palindromes found in each direction are the extremes
+fn test_observer_events() {
+    let search = Search::new(10, 99).strategy(Strategy::ProductFirst);
+    let mut recorder = Recorder::default();
+    let result = search.run_observed(&mut recorder).unwrap();
+
+    assert_eq!(recorder.found.first(), Some(&(121, vec![(11, 11)])));
+    assert_eq!(recorder.found.last(), Some(&(9009, vec![(91, 99)])));
+    assert_eq!(result.smallest().map(Palindrome::into_inner), Some(121));
+    assert!(recorder.candidates.contains(&121) && recorder.candidates.contains(&9009));
+    assert!(recorder.pruned.iter().all(|&(_, reason)| reason == PruneReason::Bound));
+    assert!(!recorder.pruned.is_empty());
+}
+
+#[test]
+/// palindrome-first searches report palindromes without accepted factors as pruned
+fn test_observer_palindrome_first() {
+    let search = Search::new(10, 99).strategy(Strategy::PalindromeFirst);
+    let mut recorder = Recorder::default();
+    search.run_observed(&mut recorder).unwrap();
+
+    assert_eq!(recorder.candidates.first(), Some(&101));
+    assert_eq!(recorder.pruned.first(), Some(&(101, PruneReason::NoFactors)));
+    assert_eq!(recorder.found, [(121, vec![(11, 11)]), (9009, vec![(91, 99)])]);
+}
+
+#[test]
+/// observers can stop the search early
+fn test_observer_early_exit() {
+    for strategy in Strategy::ALL {
+        let search = Search::new(100, 999).strategy(strategy);
+        assert_eq!(search.run_observed(&mut Budget(10)), Err(SearchError::Stopped));
+        assert!(search.run_observed(&mut Budget(usize::MAX)).is_ok());
+    }
+}
+
+#[test]
+/// a deadline stops even a search whose candidates each take long to test, or whose products overflow
+fn test_observer_deadline() {
+    for strategy in Strategy::ALL {
+        let start = Instant::now();
+        let search = Search::new(1, 1 << 63).strategy(strategy);
+        let result = search.run_observed(&mut Deadline(start + Duration::from_millis(50)));
+        assert_eq!(result, Err(SearchError::Stopped), "{strategy:?}");
+        assert!(start.elapsed() < Duration::from_secs(2), "{strategy:?} took {:?}", start.elapsed());
+    }
+}
+
+#[test]
+/// rows whose products overflow are skipped without changing the answer
+fn test_observer_overflow() {
+    let (min, max) = (u32::MAX as u64 - 300, u32::MAX as u64 + 300);
+    let mut recorder = Recorder::default();
+    let observed = Search::new(min, max).strategy(Strategy::ProductFirst).run_observed(&mut recorder).unwrap();
+    assert_eq!(observed, Search::new(min, max).strategy(Strategy::PalindromeFirst).run().unwrap());
+    assert!(recorder.pruned.iter().any(|&(_, reason)| reason == PruneReason::Overflow));
+}
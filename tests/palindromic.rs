@@ -0,0 +1,48 @@
+use palindrome_products::{Palindrome, Palindromic};
+
+/// Generic code written once against the trait.
+fn count_palindromes<T: Palindromic>(values: impl IntoIterator<Item = T>) -> usize {
+    values.into_iter().filter(Palindromic::is_palindrome).count()
+}
+
+#[test]
+/// every unsigned integer type agrees with the decimal string
+fn test_integers() {
+    for v in 0..=u16::MAX {
+        let expected = v.to_string().as_str().is_palindrome();
+        assert_eq!(v.is_palindrome(), expected, "{v}");
+        assert_eq!((v as u32).is_palindrome(), expected, "{v}");
+        assert_eq!((v as u128).is_palindrome(), expected, "{v}");
+    }
+    assert_eq!(count_palindromes(0..=u8::MAX), 10 + 9 + 16);
+}
+
+#[test]
+/// reversing an integer reports overflow
+fn test_integer_reversed() {
+    assert_eq!(1200u32.reversed(), Some(21));
+    assert_eq!(0u8.reversed(), Some(0));
+    assert_eq!(199u8.reversed(), None);
+    assert_eq!(u64::MAX.reversed(), None);
+    assert_eq!(u128::MAX.reversed(), None);
+    assert!(!u128::MAX.is_palindrome());
+    assert!(18446744066044764481u64.is_palindrome());
+}
+
+#[test]
+/// strings are compared by their chars
+fn test_strings() {
+    assert!("".is_palindrome());
+    assert!("été".is_palindrome());
+    assert!(!"Racecar".is_palindrome());
+    assert_eq!("héllo".reversed(), "olléh");
+    assert_eq!(count_palindromes(["abba", "abc", "a"]), 2);
+}
+
+#[test]
+/// palindromes are their own reversal
+fn test_palindrome() {
+    let p = Palindrome::new(9009).unwrap();
+    assert!(p.is_palindrome());
+    assert_eq!(p.reversed(), p);
+}
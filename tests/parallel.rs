@@ -0,0 +1,76 @@
+#![cfg(feature = "rayon")]
+
+use palindrome_products::parallel::{
+    count_distinct_products, count_palindrome_products_parallel, count_palindromes_in, par_palindrome_products,
+    par_palindrome_products_with,
+};
+use palindrome_products::{
+    count_palindrome_products, palindrome_products, palindrome_products_with, Palindrome, SortedProducts, Strategy,
+};
+
+#[test]
+/// the parallel search returns exactly the sequential answer
+fn test_parallel_matches_sequential() {
+    for (min, max) in [(1, 9), (10, 99), (100, 999), (0, 0), (0, 12), (15, 15), (1002, 1003), (2, 1), (90, 200)] {
+        assert_eq!(par_palindrome_products(min, max), palindrome_products(min, max), "{min}..={max}");
+    }
+}
+
+#[test]
+/// either strategy of the parallel search returns exactly the sequential answer
+fn test_parallel_with_strategy_matches_sequential() {
+    let ranges = [(1, 9), (10, 99), (100, 999), (0, 0), (0, 12), (15, 15), (1002, 1003), (2, 1), (90, 200), (0, 1)];
+    for (min, max) in ranges.into_iter().chain([(100_000, 999_999), (4_294_960_000, u32::MAX as u64)]) {
+        for strategy in Strategy::ALL.into_iter().filter(|&s| max < 100_000 || s == Strategy::PalindromeFirst) {
+            let expected = palindrome_products_with(min, max, strategy);
+            assert_eq!(par_palindrome_products_with(min, max, strategy), expected, "{strategy} {min}..={max}");
+        }
+    }
+}
+
+#[test]
+/// the parallel search handles four digit factors
+fn test_parallel_four_digit_factors() {
+    let (smallest, largest) = par_palindrome_products(1000, 9999).expect("palindromes exist");
+    assert_eq!((smallest.into_inner(), largest.into_inner()), (1002001, 99000099));
+}
+
+#[test]
+/// a single thread finds the largest product of six digit factors without scanning every row
+fn test_parallel_six_digit_factors_on_one_thread() {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+    let (smallest, largest) = pool.install(|| par_palindrome_products(100_000, 999_999)).expect("palindromes exist");
+    assert_eq!((smallest.into_inner(), largest.into_inner()), (10000200001, 999000000999));
+}
+
+#[test]
+/// counting palindromes in parallel agrees with generating them
+fn test_count_palindromes_in() {
+    for (start, end) in [(0, 0), (0, 9), (0, 1_000_000), (95, 105), (12, 21), (123, 130), (5000, 4000), (1000, 99999)] {
+        let expected = Palindrome::in_range(start..=end).count() as u64;
+        assert_eq!(count_palindromes_in(start..=end), expected, "{start}..={end}");
+    }
+    assert_eq!(count_palindromes_in(0..=u64::MAX), 11_844_674_406);
+}
+
+#[test]
+/// counting palindromic products in parallel agrees with the sequential count
+fn test_count_palindrome_products_parallel() {
+    for (min, max) in [(1, 9), (10, 99), (0, 5), (15, 15), (2, 1), (100, 999)] {
+        let expected = count_palindrome_products(min, max);
+        assert_eq!(count_palindrome_products_parallel(min, max), expected, "{min}..={max}");
+    }
+}
+
+#[test]
+/// counting the distinct products agrees with walking them, including zero and products which overflow
+fn test_count_distinct_products() {
+    let top = u32::MAX as u64 + 1;
+    for (min, max) in [(1, 9), (10, 99), (0, 5), (0, 0), (15, 15), (2, 1), (100, 999), (1, 300), (top - 40, top)] {
+        let expected = SortedProducts::new(min, max).count() as u64;
+        assert_eq!(count_distinct_products(min, max), expected, "{min}..={max}");
+    }
+    // the multiplication table of 1..=10 holds 42 distinct values
+    assert_eq!(count_distinct_products(1, 10), 42);
+    assert_eq!(count_distinct_products(u64::MAX - 5, u64::MAX), 0);
+}
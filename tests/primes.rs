@@ -0,0 +1,72 @@
+#![cfg(feature = "primes")]
+
+use palindrome_products::primes::{factorize, is_prime};
+use palindrome_products::Palindrome;
+
+fn trial_division(n: u64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
+
+#[test]
+/// primality agrees with trial division for small values
+fn test_is_prime_small() {
+    for n in 0..20_000 {
+        assert_eq!(is_prime(n), trial_division(n), "{n}");
+    }
+}
+
+#[test]
+/// large primes and composites, including strong pseudoprimes to small bases
+fn test_is_prime_large() {
+    for n in [18446744073709551557, 4294967291, 1000000007, 999999999989] {
+        assert!(is_prime(n), "{n}");
+    }
+    for n in [u64::MAX, 3215031751, 3825123056546413051, 18446744073709551559, 4294967297] {
+        assert!(!is_prime(n), "{n}");
+    }
+}
+
+#[test]
+/// the palindromic primes below a thousand
+fn test_palindrome_is_prime() {
+    let primes: Vec<u64> = Palindrome::in_range(0..=1000).filter(Palindrome::is_prime).map(Palindrome::into_inner).collect();
+    assert_eq!(
+        primes,
+        [2, 3, 5, 7, 11, 101, 131, 151, 181, 191, 313, 353, 373, 383, 727, 757, 787, 797, 919, 929]
+    );
+}
+
+#[test]
+/// factorizations multiply back to the value and list ascending primes
+fn test_factorize_small() {
+    assert_eq!(factorize(0), []);
+    assert_eq!(factorize(1), []);
+    for n in 2..5_000 {
+        let factorization = factorize(n);
+        assert!(factorization.windows(2).all(|w| w[0].0 < w[1].0), "{n}: {factorization:?}");
+        assert!(factorization.iter().all(|&(p, _)| trial_division(p)), "{n}: {factorization:?}");
+        let product: u64 = factorization.iter().map(|&(p, e)| p.pow(e)).product();
+        assert_eq!(product, n);
+    }
+}
+
+#[test]
+/// large values with large prime factors
+fn test_factorize_large() {
+    assert_eq!(factorize(1000000016000000063), [(1000000007, 1), (1000000009, 1)]);
+    assert_eq!(factorize(18446744073709551557), [(18446744073709551557, 1)]);
+    assert_eq!(factorize(u64::MAX), [(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]);
+}
+
+#[test]
+/// palindromes factorize into their prime factors
+fn test_palindrome_factorize() {
+    let factorize = |v| Palindrome::new(v).expect("is a palindrome").factorize();
+    assert_eq!(factorize(906609), [(3, 1), (11, 1), (83, 1), (331, 1)]);
+    assert_eq!(factorize(9009), [(3, 2), (7, 1), (11, 1), (13, 1)]);
+    assert_eq!(factorize(18446744066044764481), [(11, 1), (1676976733276796771, 1)]);
+    assert_eq!(
+        factorize(999999999999999999),
+        [(3, 4), (7, 1), (11, 1), (13, 1), (19, 1), (37, 1), (52579, 1), (333667, 1)]
+    );
+}
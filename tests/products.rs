@@ -0,0 +1,160 @@
+use palindrome_products::{
+    checked_sum_of_palindrome_products, count_palindrome_products, count_unreachable_palindromes, digit_range,
+    extend_max, median_palindrome_product, nth_palindrome_product, palindrome_product_map,
+    smallest_with_k_representations, sum_of_palindrome_products, unreachable_palindromes, Palindrome,
+    PalindromeProducts,
+};
+
+#[test]
+/// growing the range step by step matches a fresh search at every step
+fn test_extend_max_matches_fresh_search() {
+    let mut state = PalindromeProducts::new(10, 12);
+    for max in 13..=120 {
+        extend_max(&mut state, max);
+        assert_eq!(state, PalindromeProducts::new(10, max), "max = {max}");
+    }
+}
+
+#[test]
+/// growing the range in one large jump matches a fresh search
+fn test_extend_max_large_jump() {
+    let mut state = PalindromeProducts::new(100, 150);
+    extend_max(&mut state, 999);
+    assert_eq!(state.smallest().map(|p| p.into_inner()), Some(10201));
+    assert_eq!(state.largest().map(|p| p.into_inner()), Some(906609));
+    assert_eq!(state.max(), 999);
+}
+
+#[test]
+/// an initially empty range can grow into one with palindromes
+fn test_extend_max_from_empty_range() {
+    let mut state = PalindromeProducts::new(15, 15);
+    assert_eq!(state.smallest(), None);
+    extend_max(&mut state, 20);
+    assert_eq!(state, PalindromeProducts::new(15, 20));
+
+    let mut state = PalindromeProducts::new(10, 2);
+    extend_max(&mut state, 99);
+    assert_eq!(state, PalindromeProducts::new(10, 99));
+}
+
+#[test]
+/// shrinking the range recomputes the answer
+fn test_extend_max_shrinking() {
+    let mut state = PalindromeProducts::new(10, 99);
+    extend_max(&mut state, 20);
+    assert_eq!(state, PalindromeProducts::new(10, 20));
+}
+
+#[test]
+/// the k-th palindromic product counts distinct products from zero
+fn test_nth_palindrome_product() {
+    let nth = |min, max, k| nth_palindrome_product(min, max, k).map(|p| p.into_inner());
+    assert_eq!(nth(1, 9, 0), Some(1));
+    assert_eq!(nth(1, 9, 3), Some(4));
+    assert_eq!(nth(1, 9, 8), Some(9));
+    assert_eq!(nth(1, 9, 9), None);
+    assert_eq!(nth(10, 99, 0), Some(121));
+    assert_eq!(nth(10, 99, 1), Some(242));
+    assert_eq!(nth(15, 15, 0), None);
+}
+
+#[test]
+/// the k-th palindromic product agrees with the product map
+fn test_nth_palindrome_product_matches_map() {
+    let map = palindrome_product_map(10, 99);
+    for (k, p) in map.keys().enumerate() {
+        assert_eq!(nth_palindrome_product(10, 99, k), Some(*p));
+    }
+    assert_eq!(nth_palindrome_product(10, 99, map.len()), None);
+}
+
+#[test]
+/// the smallest palindrome with at least k factor pairs agrees with the product map
+fn test_smallest_with_k_representations() {
+    for (min, max) in [(1, 9), (10, 99), (1, 200), (0, 5), (15, 15), (2, 1)] {
+        let map = palindrome_product_map(min, max);
+        for k in 1..=6 {
+            let expected = map.iter().find(|(_, pairs)| pairs.len() >= k).map(|(p, _)| *p);
+            assert_eq!(smallest_with_k_representations(min, max, k), expected, "{min}..={max}, k = {k}");
+        }
+        assert_eq!(smallest_with_k_representations(min, max, 0), smallest_with_k_representations(min, max, 1));
+    }
+
+    let smallest = |min, max, k| smallest_with_k_representations(min, max, k).map(|p| p.into_inner());
+    // 4 = 1 x 4 = 2 x 2, and 6 = 1 x 6 = 2 x 3
+    assert_eq!(smallest(1, 9, 2), Some(4));
+    assert_eq!(smallest(2, 9, 2), None);
+    assert_eq!(smallest(10, 99, 1), Some(121));
+    // 252 = 2^2 x 3^2 x 7 has eighteen divisors, so nine factor pairs
+    assert_eq!(smallest(1, 10_000, 9), Some(252));
+}
+
+#[test]
+/// the unreachable palindromes are exactly those of the product span missing from the product map
+fn test_unreachable_palindromes() {
+    for (min, max) in [(1, 9), (10, 99), (0, 5), (15, 15), (2, 1), (100, 300)] {
+        let map = palindrome_product_map(min, max);
+        let span = (min <= max).then(|| min * min..=max * max);
+        let palindromes = span.into_iter().flat_map(Palindrome::in_range);
+        let expected: Vec<Palindrome> = palindromes.filter(|p| !map.contains_key(p)).collect();
+        assert_eq!(unreachable_palindromes(min, max).collect::<Vec<_>>(), expected, "{min}..={max}");
+        assert_eq!(count_unreachable_palindromes(min, max), expected.len(), "{min}..={max}");
+    }
+
+    let unreachable: Vec<u64> = unreachable_palindromes(1, 9).map(Palindrome::into_inner).collect();
+    assert_eq!(unreachable, [11, 22, 33, 44, 55, 66, 77]);
+    assert_eq!(count_unreachable_palindromes(15, 15), 0);
+}
+
+#[test]
+/// counting palindromic products agrees with the product map
+fn test_count_palindrome_products() {
+    for (min, max) in [(1, 9), (10, 99), (0, 5), (15, 15), (2, 1), (100, 300)] {
+        assert_eq!(count_palindrome_products(min, max), palindrome_product_map(min, max).len(), "{min}..={max}");
+    }
+}
+
+#[test]
+/// the median is the lower middle palindromic product
+fn test_median_palindrome_product() {
+    let median = |min, max| median_palindrome_product(min, max).map(|p| p.into_inner());
+    assert_eq!(median(1, 9), Some(5));
+    assert_eq!(median(1, 3), Some(3));
+    assert_eq!(median(15, 15), None);
+
+    let map = palindrome_product_map(10, 99);
+    let middle = map.keys().nth((map.len() - 1) / 2).copied();
+    assert_eq!(median_palindrome_product(10, 99), middle);
+}
+
+#[test]
+/// n-digit factor ranges span exactly the numbers with that many digits
+fn test_digit_range() {
+    assert_eq!(digit_range(0), None);
+    assert_eq!(digit_range(1), Some(1..=9));
+    assert_eq!(digit_range(3), Some(100..=999));
+    assert_eq!(digit_range(19), Some(1_000_000_000_000_000_000..=9_999_999_999_999_999_999));
+    assert_eq!(digit_range(20), Some(10_000_000_000_000_000_000..=u64::MAX));
+    assert_eq!(digit_range(21), None);
+}
+
+#[test]
+/// searching by digit count matches searching the explicit range
+fn test_with_digits() {
+    assert_eq!(PalindromeProducts::with_digits(2), Some(PalindromeProducts::new(10, 99)));
+    let three = PalindromeProducts::with_digits(3).unwrap();
+    assert_eq!(three.largest().map(|p| p.into_inner()), Some(906609));
+    assert_eq!(PalindromeProducts::with_digits(0), None);
+}
+
+#[test]
+/// sums of the distinct palindromic products agree with the product map
+fn test_sum_of_palindrome_products() {
+    for (min, max) in [(1, 9), (10, 99), (100, 200), (2, 1), (15, 15)] {
+        let expected: u64 = palindrome_product_map(min, max).keys().sum();
+        assert_eq!(sum_of_palindrome_products(min, max), expected as u128, "{min}..={max}");
+        assert_eq!(checked_sum_of_palindrome_products(min, max), Some(expected), "{min}..={max}");
+    }
+    assert_eq!(sum_of_palindrome_products(1, 9), 1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9);
+}
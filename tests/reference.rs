@@ -0,0 +1,69 @@
+#![cfg(feature = "reference")]
+
+use palindrome_products::{palindrome_products, reference, Palindrome, SortedProducts};
+
+/// A tiny deterministic generator, so failures are reproducible.
+fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+/// Random ranges, mostly small and occasionally empty.
+fn random_ranges() -> impl Iterator<Item = (u64, u64)> {
+    let mut seed = 0x2545_f491_4f6c_dd1d;
+    std::iter::repeat_with(move || {
+        let min = next_random(&mut seed) % 200;
+        let max = min + next_random(&mut seed) % 60;
+        match next_random(&mut seed) % 10 {
+            0 => (max, min),
+            _ => (min, max),
+        }
+    })
+    .take(200)
+}
+
+#[test]
+/// the optimized search agrees with the brute force one
+fn test_palindrome_products_matches_reference() {
+    for (min, max) in random_ranges() {
+        assert_eq!(palindrome_products(min, max), reference::palindrome_products(min, max), "{min}..={max}");
+    }
+}
+
+#[test]
+/// the ordered product enumeration agrees with sorting every product
+fn test_sorted_products_matches_reference() {
+    for (min, max) in random_ranges().filter(|&(min, _)| min > 0) {
+        let ascending: Vec<u64> = SortedProducts::new(min, max).collect();
+        let mut descending: Vec<u64> = SortedProducts::new(min, max).rev().collect();
+        descending.reverse();
+        let expected = reference::sorted_products(min, max);
+        assert_eq!(ascending, expected, "{min}..={max}");
+        assert_eq!(descending, expected, "{min}..={max}");
+    }
+}
+
+#[test]
+/// factor pairs agree with checking every pair
+fn test_factors_match_reference() {
+    for (min, max) in random_ranges() {
+        for p in Palindrome::iter_from(min.saturating_mul(min)).take(20) {
+            assert_eq!(p.factors(min, max), reference::factors(p.into_inner(), min, max), "{p} in {min}..={max}");
+        }
+    }
+}
+
+#[test]
+/// chunked enumeration splits the sorted products into blocks of the requested size
+fn test_sorted_product_chunks_match_reference() {
+    for (min, max) in random_ranges().filter(|&(min, _)| min > 0) {
+        let expected = reference::sorted_products(min, max);
+        for size in [1, 7, 64] {
+            let chunks: Vec<Vec<u64>> = SortedProducts::new(min, max).chunks(size).collect();
+            assert!(chunks.iter().rev().skip(1).all(|chunk| chunk.len() == size), "{min}..={max} by {size}");
+            assert_eq!(chunks.concat(), expected, "{min}..={max} by {size}");
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use palindrome_products::report::{render_html, render_markdown};
+use palindrome_products::PalindromeProducts;
+
+#[test]
+/// the markdown report tabulates the extremes and the range
+fn test_render_markdown() {
+    let expected = "\
+| | palindrome | factor pairs |
+|---|---:|---|
+| smallest | 121 | 11 × 11 |
+| largest | 9009 | 91 × 99 |
+
+| range | factors | factor pairs |
+|---|---:|---:|
+| 10..=99 | 90 | 4095 |
+";
+    assert_eq!(render_markdown(&PalindromeProducts::new(10, 99)), expected);
+}
+
+#[test]
+/// missing palindromes are reported as none
+fn test_render_markdown_without_palindromes() {
+    let report = render_markdown(&PalindromeProducts::new(15, 15));
+    assert!(report.contains("| smallest | none |  |\n"), "{report}");
+    assert!(report.contains("| 15..=15 | 1 | 1 |\n"), "{report}");
+}
+
+#[test]
+/// the html report lists every factor pair
+fn test_render_html() {
+    let report = render_html(&PalindromeProducts::new(1, 9));
+    assert!(report.starts_with("<table>\n"));
+    assert!(report.contains("<tr><th>smallest</th><td>1</td><td>1 × 1</td></tr>"), "{report}");
+    assert!(report.contains("<tr><th>largest</th><td>9</td><td>1 × 9, 3 × 3</td></tr>"), "{report}");
+    assert!(report.contains("<tr><td>1..=9</td><td>9</td><td>45</td></tr>"), "{report}");
+    assert!(report.ends_with("</table>\n"));
+}
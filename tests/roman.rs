@@ -0,0 +1,30 @@
+use palindrome_products::roman::{is_roman_palindrome, roman_palindromes, to_roman};
+
+#[test]
+/// values render in subtractive notation and out-of-range values have no numeral
+fn test_to_roman() {
+    assert_eq!(to_roman(19).as_deref(), Some("XIX"));
+    assert_eq!(to_roman(1994).as_deref(), Some("MCMXCIV"));
+    assert_eq!(to_roman(3999).as_deref(), Some("MMMCMXCIX"));
+    assert_eq!(to_roman(0), None);
+    assert_eq!(to_roman(4000), None);
+}
+
+#[test]
+/// test `is_roman_palindrome` with symmetric and asymmetric numerals
+fn test_is_roman_palindrome() {
+    for v in [1, 2, 3, 5, 10, 19, 20, 190, 2000] {
+        assert!(is_roman_palindrome(v), "{v}");
+    }
+    for v in [0, 4, 6, 9, 14, 4000] {
+        assert!(!is_roman_palindrome(v), "{v}");
+    }
+}
+
+#[test]
+/// the iterator yields roman palindromes in ascending order
+fn test_roman_palindromes() {
+    let first: Vec<u64> = roman_palindromes().take(8).collect();
+    assert_eq!(first, [1, 2, 3, 5, 10, 19, 20, 30]);
+    assert!(roman_palindromes().all(|n| n <= 3999));
+}
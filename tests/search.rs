@@ -0,0 +1,193 @@
+use palindrome_products::{
+    palindrome_products_with, Palindrome, PalindromeProducts, Search, SearchError, SortedProducts, Strategy,
+    DEFAULT_SEED,
+};
+
+#[test]
+/// an unconfigured search gives the monolithic answer
+fn test_search_default() {
+    for (min, max) in [(1, 9), (10, 99), (15, 15), (2, 1)] {
+        assert_eq!(Search::new(min, max).run(), Ok(PalindromeProducts::new(min, max)));
+        for strategy in Strategy::ALL {
+            assert_eq!(Search::new(min, max).strategy(strategy).run(), Ok(PalindromeProducts::new(min, max)));
+        }
+    }
+}
+
+#[test]
+/// exceeding the memory limit falls back to the bounded-memory strategy
+fn test_search_memory_limit_fallback() {
+    for (min, max) in [(100, 999), (1 << 32, (1 << 32) + 999)] {
+        let result = Search::new(min, max).max_memory(100).run().unwrap();
+        let expected = palindrome_products_with(min, max, Strategy::PalindromeFirst);
+        assert_eq!(result.smallest().zip(result.largest()), expected);
+    }
+}
+
+#[test]
+/// exceeding the memory limit with an explicit strategy is an error
+fn test_search_memory_limit_error() {
+    let result = Search::new(100, 999).strategy(Strategy::ProductFirst).max_memory(100).run();
+    let Err(SearchError::MemoryLimit { required, limit: 100 }) = result else {
+        panic!("expected a memory limit error, got {result:?}");
+    };
+    assert!(required > 100);
+    let search = Search::new(100, 999).strategy(Strategy::ProductFirst).max_memory(required);
+    assert_eq!(search.run(), Ok(PalindromeProducts::new(100, 999)));
+
+    // wide factors fall back to the generic enumeration, which needs more memory for each factor
+    let (min, max) = (1 << 32, (1 << 32) + 999);
+    let result = Search::new(min, max).strategy(Strategy::ProductFirst).max_memory(100).run();
+    let required_wide = SortedProducts::memory_estimate(min, max);
+    assert_eq!(result, Err(SearchError::MemoryLimit { required: required_wide, limit: 100 }));
+    assert!(required_wide > required);
+}
+
+/// Brute-force extremes over every accepted pair of factors.
+fn brute_force(min: u64, max: u64, accepts: impl Fn(u64) -> bool) -> (Option<Palindrome>, Option<Palindrome>) {
+    let products = (min..=max)
+        .flat_map(|a| (a..=max).map(move |b| (a, b)))
+        .filter(|&(a, b)| accepts(a) && accepts(b))
+        .filter_map(|(a, b)| Palindrome::new(a * b));
+    let (smallest, largest) = products.fold((None, None), |(lo, hi): (Option<Palindrome>, Option<Palindrome>), p| {
+        (Some(lo.map_or(p, |lo| lo.min(p))), Some(hi.map_or(p, |hi| hi.max(p))))
+    });
+    (smallest, largest)
+}
+
+#[test]
+/// restricting the search to palindromic factors agrees with brute force for every strategy
+fn test_search_palindromic_factors() {
+    let is_palindrome = |f: u64| Palindrome::new(f).is_some();
+    for (min, max) in [(1, 9), (10, 99), (100, 999), (0, 30), (12, 20), (5, 4)] {
+        let expected = brute_force(min, max, is_palindrome);
+        for strategy in Strategy::ALL {
+            let result = Search::new(min, max).palindromic_factors(true).strategy(strategy).run().unwrap();
+            assert_eq!((result.smallest(), result.largest()), expected, "{min}..={max} {strategy}");
+        }
+    }
+}
+
+#[test]
+/// returned factor pairs only use palindromic factors
+fn test_search_palindromic_factor_pairs() {
+    let search = Search::new(10, 200).palindromic_factors(true);
+    let smallest = search.run().unwrap().smallest().unwrap();
+    assert_eq!(smallest.into_inner(), 121);
+    assert_eq!(search.factors(smallest), [(11, 11)]);
+    assert_eq!(search.factors(Palindrome::new(1331).unwrap()), [(11, 121)]);
+    assert_eq!(search.factors(Palindrome::new(2002).unwrap()), []);
+}
+
+#[test]
+/// a stepped factor range agrees with brute force over the same factors
+fn test_search_step() {
+    for (min, max, step) in [(100, 999, 5), (101, 999, 2), (100, 998, 2), (10, 99, 7), (0, 50, 3), (1, 9, 100)] {
+        let expected = brute_force(min, max, |f| (f - min) % step as u64 == 0);
+        for strategy in Strategy::ALL {
+            let result = Search::new(min, max).step(step).strategy(strategy).run().unwrap();
+            assert_eq!((result.smallest(), result.largest()), expected, "{min}..={max} by {step} {strategy}");
+        }
+    }
+}
+
+#[test]
+/// a step composes with palindromic factors, and factor pairs stay on the step
+fn test_search_step_factor_pairs() {
+    let expected = brute_force(1, 300, |f| (f - 1) % 10 == 0 && Palindrome::new(f).is_some());
+    let search = Search::new(1, 300).step(10).palindromic_factors(true);
+    let result = search.run().unwrap();
+    assert_eq!((result.smallest(), result.largest()), expected);
+
+    let search = Search::new(100, 999).step(5);
+    let largest = search.run().unwrap().largest().unwrap();
+    assert!(search.factors(largest).iter().all(|&(a, b)| a % 5 == 0 && b % 5 == 0));
+    assert!(!search.factors(largest).is_empty());
+}
+
+#[test]
+/// excluded factors take no part in the search nor in the returned factor pairs
+fn test_search_exclude_factors() {
+    let excluded = [91, 99, 913, 993];
+    for (min, max) in [(10, 99), (100, 999), (90, 100)] {
+        let expected = brute_force(min, max, |f| !excluded.contains(&f));
+        for strategy in Strategy::ALL {
+            let result = Search::new(min, max).exclude_factors(&excluded).strategy(strategy).run().unwrap();
+            assert_eq!((result.smallest(), result.largest()), expected, "{min}..={max} {strategy}");
+        }
+    }
+
+    let search = Search::new(10, 99).exclude_factors(&[91]).exclude_factors(&[77]);
+    assert_eq!(Palindrome::new(1001).unwrap().factors(10, 99), [(11, 91), (13, 77)]);
+    assert_eq!(search.factors(Palindrome::new(1001).unwrap()), []);
+    assert_eq!(search.factors(Palindrome::new(9009).unwrap()), []);
+    assert_eq!(search.factors(Palindrome::new(121).unwrap()), [(11, 11)]);
+}
+
+#[test]
+/// a minimum digit count skips the shorter palindromes, agreeing with brute force for every strategy
+fn test_search_min_digits() {
+    for (min, max, digits) in [(1, 9, 2), (1, 9, 3), (1, 30, 3), (0, 5, 2), (0, 5, 1), (10, 99, 4), (10, 99, 5), (100, 999, 6)] {
+        let products = (min..=max).flat_map(|a| (a..=max).map(move |b| a * b));
+        let mut palindromes: Vec<Palindrome> =
+            products.filter_map(Palindrome::new).filter(|p| p.to_string().len() >= digits as usize).collect();
+        palindromes.sort();
+        let expected = (palindromes.first().copied(), palindromes.last().copied());
+        for strategy in Strategy::ALL {
+            let result = Search::new(min, max).min_digits(digits).strategy(strategy).run().unwrap();
+            assert_eq!((result.smallest(), result.largest()), expected, "{min}..={max} {digits} digits {strategy}");
+        }
+    }
+
+    assert_eq!(Search::new(1, 20).run().unwrap().smallest().map(u64::from), Some(1));
+    assert_eq!(Search::new(1, 20).min_digits(2).run().unwrap().smallest().map(u64::from), Some(11));
+    for digits in [0, 1] {
+        assert_eq!(Search::new(1, 9).min_digits(digits).run(), Search::new(1, 9).run());
+    }
+    // no u64 has 21 digits
+    assert_eq!(Search::new(1, 9).min_digits(21).run().unwrap().largest(), None);
+}
+
+#[test]
+/// the density estimate depends only on the seed and the options
+fn test_search_density_reproducible() {
+    let search = Search::new(10, 99);
+    assert_eq!(search.estimate_density(1000), Search::new(10, 99).estimate_density(1000));
+    assert_eq!(search.estimate_density(1000), search.clone().seed(DEFAULT_SEED).estimate_density(1000));
+    assert_eq!(search.clone().seed(7).estimate_density(1000), search.clone().seed(7).estimate_density(1000));
+    assert_ne!(search.clone().seed(7).estimate_density(1000), search.clone().seed(8).estimate_density(1000));
+}
+
+#[test]
+/// the density estimate approaches the exact fraction of palindromic pairs
+fn test_search_density_estimate() {
+    let (min, max) = (10u64, 99u64);
+    let mut palindromic = 0;
+    for a in min..=max {
+        for b in min..=max {
+            palindromic += Palindrome::new(a * b).is_some() as u32;
+        }
+    }
+    let exact = palindromic as f64 / ((max - min + 1) * (max - min + 1)) as f64;
+    let estimate = Search::new(min, max).seed(1).estimate_density(100_000).unwrap();
+    assert!((estimate - exact).abs() < 0.01, "{estimate} vs {exact}");
+}
+
+#[test]
+/// the density estimate counts only accepted pairs, and there is none without accepted factors
+fn test_search_density_constraints() {
+    assert_eq!(Search::new(2, 1).estimate_density(100), None);
+    assert_eq!(Search::new(10, 99).estimate_density(0), None);
+    assert_eq!(Search::new(10, 10).exclude_factors(&[10]).estimate_density(100), None);
+    // a single accepted factor always gives the same product: 121 is a palindrome and 144 is not
+    assert_eq!(Search::new(11, 11).estimate_density(10), Some(1.0));
+    assert_eq!(Search::new(10, 12).step(2).exclude_factors(&[10]).estimate_density(10), Some(0.0));
+}
+
+#[test]
+/// the density estimate covers the full range of a `u64` without overflowing
+fn test_search_density_full_range() {
+    let density = Search::new(0, u64::MAX).estimate_density(1000).unwrap();
+    assert!((0.0..=1.0).contains(&density), "{density}");
+    assert!(Search::new(0, u64::MAX).step(3).estimate_density(1000).is_some());
+}
@@ -0,0 +1,111 @@
+use palindrome_products::sequences::{
+    longest_palindrome_free_run, palindromic_powers, palindromic_pronics, palindromic_square_sums,
+    palindromic_triangulars, smallest_palindromic_multiple,
+};
+use palindrome_products::Palindrome;
+
+fn roots(pairs: impl Iterator<Item = (u64, Palindrome)>) -> Vec<u64> {
+    pairs.map(|(n, _)| n).collect()
+}
+
+#[test]
+/// numbers whose square is a palindrome
+fn test_palindromic_squares() {
+    assert_eq!(roots(palindromic_powers(2, 0..=300)), [0, 1, 2, 3, 11, 22, 26, 101, 111, 121, 202, 212, 264]);
+}
+
+#[test]
+/// numbers whose cube is a palindrome, with the palindromic power
+fn test_palindromic_cubes() {
+    let cubes: Vec<(u64, u64)> = palindromic_powers(3, 1..=2000).map(|(n, p)| (n, p.into_inner())).collect();
+    assert_eq!(cubes, [(1, 1), (2, 8), (7, 343), (11, 1331), (101, 1030301), (111, 1367631), (1001, 1003003001)]);
+}
+
+#[test]
+/// iteration stops once the power overflows
+fn test_palindromic_powers_overflow() {
+    assert_eq!(palindromic_powers(5, u64::MAX - 10..=u64::MAX).count(), 0);
+    assert!(palindromic_powers(4, 0..=u64::MAX).last().is_some());
+}
+
+#[test]
+/// palindromic pronic numbers match multiplying every n, including zero and the overflow at the top of the range
+fn test_palindromic_pronics() {
+    let found: Vec<(u64, u64)> = palindromic_pronics(0..=100_000).map(|(n, p)| (n, p.into_inner())).collect();
+    let expected: Vec<(u64, u64)> =
+        (0..=100_000u64).map(|n| (n, n * (n + 1))).filter(|&(_, pronic)| Palindrome::new(pronic).is_some()).collect();
+    assert_eq!(found, expected);
+    assert_eq!(found[..6], [(0, 0), (1, 2), (2, 6), (16, 272), (77, 6006), (538, 289982)]);
+
+    assert_eq!(palindromic_pronics(5..=15).count(), 0);
+    // the pronic number of 2^32 overflows, so iteration stops just short of it
+    let top = 1u64 << 32;
+    assert!(palindromic_pronics(top - 1_000..=u64::MAX).all(|(n, _)| n < top));
+}
+
+#[test]
+/// palindromic triangular numbers come with their index, up to and including the limit
+fn test_palindromic_triangulars() {
+    let found: Vec<(u64, u64)> = palindromic_triangulars(1_000).map(|(k, p)| (k, p.into_inner())).collect();
+    assert_eq!(found, [(0, 0), (1, 1), (2, 3), (3, 6), (10, 55), (11, 66), (18, 171), (34, 595), (36, 666)]);
+    assert_eq!(palindromic_triangulars(666).last().map(|(k, _)| k), Some(36));
+    assert_eq!(palindromic_triangulars(665).last().map(|(k, _)| k), Some(34));
+
+    let expected = (0..20_000u64).map(|k| (k, k * (k + 1) / 2)).filter(|&(_, t)| Palindrome::new(t).is_some());
+    let found = palindromic_triangulars(19_999 * 20_000 / 2).map(|(k, p)| (k, p.into_inner()));
+    assert!(found.eq(expected));
+}
+
+#[test]
+/// finds the least palindromic multiple within the limit
+fn test_smallest_palindromic_multiple() {
+    let found = |n, limit| smallest_palindromic_multiple(n, limit).map(|(p, k)| (p.into_inner(), k));
+    assert_eq!(found(1, 100), Some((1, 1)));
+    assert_eq!(found(12, 1000), Some((252, 21)));
+    assert_eq!(found(99, 10_000), Some((99, 1)));
+    assert_eq!(found(17, 10_000), Some((272, 16)));
+    assert_eq!(found(12, 251), None);
+}
+
+#[test]
+/// zero and multiples of ten have no palindromic multiple
+fn test_smallest_palindromic_multiple_none() {
+    assert_eq!(smallest_palindromic_multiple(0, u64::MAX), None);
+    assert_eq!(smallest_palindromic_multiple(20, u64::MAX), None);
+}
+
+#[test]
+/// palindromes which are sums of consecutive squares, as in Project Euler 125
+fn test_palindromic_square_sums() {
+    let below = |limit| palindromic_square_sums(limit).into_iter().map(Palindrome::into_inner).collect::<Vec<u64>>();
+    assert_eq!(below(1000), [5, 55, 77, 181, 313, 434, 505, 545, 595, 636, 818]);
+    assert_eq!(below(595), [5, 55, 77, 181, 313, 434, 505, 545]);
+    assert!(below(0).is_empty());
+    assert!(below(5).is_empty());
+
+    let sums = below(100_000_000);
+    assert_eq!(sums.len(), 166);
+    assert_eq!(sums.iter().sum::<u64>(), 2_906_969_179);
+}
+
+#[test]
+/// the longest runs without palindromes match a scan of every integer
+fn test_longest_palindrome_free_run() {
+    let scan = |limit: u64| {
+        let (mut longest, mut start) = ((0, 0), 0);
+        for n in 0..=limit {
+            if n == limit || Palindrome::new(n).is_some() {
+                if n - start > longest.1 {
+                    longest = (start, n - start);
+                }
+                start = n + 1;
+            }
+        }
+        longest
+    };
+    for limit in [0, 1, 10, 11, 12, 25, 100, 101, 1000, 12_345, 100_000] {
+        assert_eq!(longest_palindrome_free_run(limit), scan(limit), "{limit}");
+    }
+    assert_eq!(longest_palindrome_free_run(10), (0, 0));
+    assert_eq!(longest_palindrome_free_run(1000), (12, 10));
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "server")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+
+/// A running server, killed when dropped.
+struct Server {
+    child: Child,
+    address: String,
+}
+
+impl Server {
+    fn start(args: &[&str]) -> Server {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_palindrome-products-server"))
+            .args([&["--listen", "127.0.0.1:0"][..], args].concat())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("start the server");
+        let mut line = String::new();
+        BufReader::new(child.stderr.as_mut().unwrap()).read_line(&mut line).unwrap();
+        let address = line.trim().strip_prefix("listening on ").expect("the address").to_string();
+        Server { child, address }
+    }
+
+    /// GET `path`, returning the status code and the body.
+    fn get(&self, path: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(&self.address).unwrap();
+        write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response.split(' ').nth(1).and_then(|code| code.parse().ok()).expect("a status line");
+        let body = response.split_once("\r\n\r\n").map_or("", |(_, body)| body).to_string();
+        (status, body)
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+/// the service answers with the JSON report of the range, or explains what is wrong with the query
+fn test_server() {
+    let server = Server::start(&[]);
+    let (status, body) = server.get("/palindrome-products?min=10&max=99");
+    assert_eq!(status, 200);
+    let report: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(report, palindrome_products::PalindromeProducts::new(10, 99).to_json());
+
+    for (query, message) in [
+        ("min=10", "missing query parameter max"),
+        ("min=ten&max=99", "invalid value \"ten\" for min"),
+        ("min=99&max=10", "min 99 is above max 10, so the range is empty"),
+    ] {
+        let (status, body) = server.get(&format!("/palindrome-products?{query}"));
+        assert_eq!(status, 400, "{query}");
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&body).unwrap()["error"], message, "{query}");
+    }
+    assert_eq!(server.get("/elsewhere").0, 404);
+}
+
+#[test]
+/// a search which outlasts the request timeout gives up
+fn test_server_timeout() {
+    let server = Server::start(&["--timeout", "0"]);
+    let (status, body) = server.get("/palindrome-products?min=100&max=999");
+    assert_eq!(status, 503);
+    assert!(body.contains("the search took longer than 0s"), "{body}");
+}
@@ -0,0 +1,91 @@
+use palindrome_products::{Palindrome, PalindromeSet};
+
+fn palindromes(values: &[u64]) -> Vec<Palindrome> {
+    values.iter().map(|&v| Palindrome::new(v).expect("is a palindrome")).collect()
+}
+
+fn sample() -> PalindromeSet {
+    let mut set = PalindromeSet::new();
+    for p in palindromes(&[9009, 0, 7, 121, 18446744066044764481, 1001, 99, 10201, 121]) {
+        set.insert(p);
+    }
+    set
+}
+
+#[test]
+/// insertion deduplicates and iteration is ascending
+fn test_palindrome_set_insert_and_iter() {
+    let set = sample();
+    assert_eq!(set.len(), 8);
+    let values: Vec<u64> = set.iter().map(Palindrome::into_inner).collect();
+    assert_eq!(values, [0, 7, 99, 121, 1001, 9009, 10201, 18446744066044764481]);
+}
+
+#[test]
+/// membership and removal
+fn test_palindrome_set_contains_and_remove() {
+    let mut set = sample();
+    let p = Palindrome::new(1001).expect("is a palindrome");
+    assert!(set.contains(p));
+    assert!(set.remove(p));
+    assert!(!set.contains(p));
+    assert!(!set.remove(p));
+    assert!(!set.contains(Palindrome::new(1111).expect("is a palindrome")));
+    assert_eq!(set.len(), 7);
+}
+
+#[test]
+/// range queries respect inclusive and exclusive bounds
+fn test_palindrome_set_ranges() {
+    let set = sample();
+    assert_eq!(set.count_between(7, 9009), 5);
+    assert_eq!(set.count_between(8, 120), 1);
+    assert_eq!(set.count_between(122, 1000), 0);
+    assert_eq!(set.count_between(9009, 7), 0);
+    assert_eq!(set.count_between(0, u64::MAX), 8);
+
+    let values: Vec<u64> = set.iter_range(99..10201).map(Palindrome::into_inner).collect();
+    assert_eq!(values, [99, 121, 1001, 9009]);
+    let values: Vec<u64> = set.iter_range(10000..).rev().map(Palindrome::into_inner).collect();
+    assert_eq!(values, [18446744066044764481, 10201]);
+}
+
+#[test]
+/// the set agrees with a sorted vector over every palindrome below a bound
+fn test_palindrome_set_matches_sorted_vec() {
+    let all: Vec<Palindrome> = Palindrome::iter_from(0).take_while(|p| p.into_inner() < 200_000).collect();
+    let mut set = PalindromeSet::new();
+    for p in all.iter().rev() {
+        assert!(set.insert(*p));
+    }
+    assert_eq!(set.iter().collect::<Vec<_>>(), all);
+    let expected = all.iter().filter(|p| (500..=150_000).contains(&p.into_inner())).count();
+    assert_eq!(set.count_between(500, 150_000), expected);
+}
+
+#[test]
+/// sets collect from iterators, extend, and combine with the usual set operations
+fn test_palindrome_set_operations() {
+    let below = || Palindrome::iter_from(0).take_while(|p| p.into_inner() < 20_000);
+    let expected = |keep: fn(u64) -> bool| -> Vec<Palindrome> { below().filter(|p| keep(p.into_inner())).collect() };
+    let evens: PalindromeSet = below().filter(|p| p.into_inner() % 2 == 0).collect();
+    let threes: PalindromeSet = below().filter(|p| p.into_inner() % 3 == 0).collect();
+
+    let union = evens.union(&threes);
+    assert_eq!(union.iter().collect::<Vec<_>>(), expected(|v| v % 2 == 0 || v % 3 == 0));
+    assert_eq!(union.len(), union.iter().count());
+    assert_eq!(&evens | &threes, union);
+    let intersection = evens.intersection(&threes);
+    assert_eq!(intersection.iter().collect::<Vec<_>>(), expected(|v| v % 6 == 0));
+    assert_eq!(&evens & &threes, intersection);
+    let difference = evens.difference(&threes);
+    assert_eq!(difference.iter().collect::<Vec<_>>(), expected(|v| v % 2 == 0 && v % 3 != 0));
+    assert_eq!(&evens - &threes, difference);
+    assert!(evens.difference(&evens).is_empty());
+    assert_eq!(evens.union(&PalindromeSet::new()), evens);
+
+    let mut set = sample();
+    set.extend(palindromes(&[121, 55, 18446744066044764481]));
+    assert_eq!(set.len(), 9);
+    assert!(set.contains(Palindrome::new(55).expect("is a palindrome")));
+}
@@ -0,0 +1,53 @@
+use palindrome_products::{merge_shard_results, shard_range, MergeError, PalindromeProducts, Shard, ShardResult};
+
+#[test]
+/// shards cover the range contiguously without empty shards
+fn test_shard_range_covers_range() {
+    for (min, max, count) in [(10, 99, 4), (100, 999, 7), (1, 3, 10), (5, 5, 1), (1, 1000, 0)] {
+        let shards = shard_range(min, max, count);
+        assert!(!shards.is_empty() && shards.len() <= count.max(1), "{min}..={max} / {count}");
+        assert_eq!(shards[0].from(), min);
+        assert_eq!(shards.last().map(Shard::to), Some(max));
+        for pair in shards.windows(2) {
+            assert_eq!(pair[0].to() + 1, pair[1].from());
+        }
+        assert!(shards.iter().all(|shard| shard.from() <= shard.to() && shard.min() == min));
+    }
+}
+
+#[test]
+/// shards near the top of the range are narrower, balancing the work
+fn test_shard_range_balances_work() {
+    let shards = shard_range(1, 1000, 4);
+    let widths: Vec<u64> = shards.iter().map(|shard| shard.to() - shard.from() + 1).collect();
+    assert_eq!(widths.len(), 4);
+    assert!(widths.windows(2).all(|w| w[0] > w[1]), "{widths:?}");
+}
+
+#[test]
+/// merged shard results equal the monolithic answer, whatever order they arrive in
+fn test_merge_equals_monolithic() {
+    for (min, max, count) in [(10, 99, 4), (100, 999, 5), (1, 9, 3), (15, 15, 2), (10, 2, 3), (90, 130, 40)] {
+        let mut results: Vec<ShardResult> = shard_range(min, max, count).iter().map(Shard::search).collect();
+        results.reverse();
+        assert_eq!(merge_shard_results(results), Ok(PalindromeProducts::new(min, max)), "{min}..={max} / {count}");
+    }
+}
+
+#[test]
+/// missing, overlapping or foreign shards are rejected
+fn test_merge_rejects_incomplete_results() {
+    let results: Vec<ShardResult> = shard_range(10, 99, 3).iter().map(Shard::search).collect();
+    assert_eq!(merge_shard_results([]), Err(MergeError::NoShards));
+
+    let missing_middle = [results[0], results[2]];
+    assert!(matches!(merge_shard_results(missing_middle), Err(MergeError::NotContiguous { .. })));
+    let missing_first = [results[1], results[2]];
+    assert!(matches!(merge_shard_results(missing_first), Err(MergeError::NotContiguous { .. })));
+    let duplicated = [results[0], results[1], results[1], results[2]];
+    assert!(matches!(merge_shard_results(duplicated), Err(MergeError::NotContiguous { .. })));
+
+    let foreign = Shard::new(11, results[2].shard().to() + 1, 120).search();
+    let mixed = [results[0], results[1], results[2], foreign];
+    assert_eq!(merge_shard_results(mixed), Err(MergeError::MismatchedMin { expected: 10, found: 11 }));
+}
@@ -0,0 +1,64 @@
+use palindrome_products::{
+    palindrome_products, palindrome_products_u32, palindrome_products_with, Palindrome, SortedProducts, Strategy,
+};
+
+#[test]
+/// every strategy returns the answer of the default search
+fn test_strategies_agree() {
+    for (min, max) in [(1, 9), (10, 99), (100, 999), (0, 0), (0, 12), (15, 15), (1002, 1003), (2, 1), (90, 200)] {
+        for strategy in Strategy::ALL {
+            let expected = palindrome_products(min, max);
+            assert_eq!(palindrome_products_with(min, max, strategy), expected, "{strategy} {min}..={max}");
+        }
+    }
+}
+
+#[test]
+/// the palindrome-first strategy handles wide ranges
+fn test_palindrome_first_wide_range() {
+    let (smallest, largest) = palindrome_products_with(1000, 9999, Strategy::PalindromeFirst).expect("palindromes exist");
+    assert_eq!((smallest.into_inner(), largest.into_inner()), (1002001, 99000099));
+}
+
+#[test]
+/// strategies round trip through their names
+fn test_strategy_names() {
+    for strategy in Strategy::ALL {
+        assert_eq!(strategy.to_string().parse(), Ok(strategy));
+    }
+    assert_eq!("palindrome-first".parse(), Ok(Strategy::PalindromeFirst));
+    assert!("fastest".parse::<Strategy>().is_err());
+}
+
+#[test]
+/// the 32-bit search agrees with the generic enumeration
+fn test_u32_search_matches_generic() {
+    for (min, max) in [(1, 9), (10, 99), (100, 999), (0, 0), (0, 12), (15, 15), (1002, 1003), (2, 1), (90, 200), (0, 1)] {
+        let expected = SortedProducts::new(min as u64, max as u64).find_map(Palindrome::new).zip(
+            SortedProducts::new(min as u64, max as u64).rev().find_map(Palindrome::new),
+        );
+        assert_eq!(palindrome_products_u32(min, max), expected, "{min}..={max}");
+    }
+}
+
+#[test]
+/// the 32-bit search handles factors at the top of the u32 range
+fn test_u32_search_near_u32_max() {
+    let (min, max) = (u32::MAX - 300, u32::MAX);
+    let generic = palindrome_products_with((1 << 32) - 301, (1 << 32) - 1, Strategy::PalindromeFirst);
+    assert_eq!(palindrome_products_u32(min, max), generic);
+}
+
+#[test]
+/// only a handful of factors are searched product-first by default, and the default agrees with both strategies
+fn test_strategy_for_range() {
+    assert_eq!(Strategy::for_range(15, 15), Strategy::ProductFirst);
+    assert_eq!(Strategy::for_range(100, 114), Strategy::ProductFirst);
+    assert_eq!(Strategy::for_range(100, 115), Strategy::PalindromeFirst);
+    assert_eq!(Strategy::for_range(2, 1), Strategy::ProductFirst);
+    assert_eq!(Strategy::for_range(0, u64::MAX), Strategy::PalindromeFirst);
+    for (min, max) in [(1, 9), (100, 114), (100, 999), (1000000, 9999999)] {
+        let expected = palindrome_products_with(min, max, Strategy::PalindromeFirst);
+        assert_eq!(palindrome_products(min, max), expected, "{min}..={max}");
+    }
+}
@@ -0,0 +1,63 @@
+use palindrome_products::{Palindrome, StreamChecker};
+
+/// Deterministic pseudo-random bytes from a xorshift generator.
+fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+#[test]
+/// every prefix of a digit stream is checked like the number it spells
+fn test_digit_stream() {
+    for value in 0..20_000u64 {
+        let mut checker = StreamChecker::new();
+        checker.extend(value.to_string().bytes().map(|b| b - b'0'));
+        assert_eq!(checker.is_palindrome(), Palindrome::new(value).is_some(), "{value}");
+        assert_eq!(checker.len(), value.to_string().len() as u64);
+    }
+}
+
+#[test]
+/// zeros count, so 10 and 1 are told apart, and the empty stream is a palindrome
+fn test_zeros_and_empty() {
+    let mut checker = StreamChecker::default();
+    assert!(checker.is_empty() && checker.is_palindrome());
+    checker.push(1);
+    assert!(checker.is_palindrome());
+    checker.push(0);
+    assert!(!checker.is_palindrome());
+    checker.push(0);
+    assert!(!checker.is_palindrome());
+    checker.push(1);
+    assert!(checker.is_palindrome());
+}
+
+#[test]
+/// long byte streams agree with buffering and reversing, for any seed
+fn test_byte_streams() {
+    let mut state = 0x2545_f491_4f6c_dd1d;
+    for seed in [0, 1, 42] {
+        for len in [1, 2, 100, 1001] {
+            let half: Vec<u8> = (0..len / 2).map(|_| next_random(&mut state) as u8).collect();
+            let mut bytes = half.clone();
+            bytes.extend((len % 2 == 1).then_some(7));
+            bytes.extend(half.iter().rev());
+
+            let mut checker = StreamChecker::with_seed(seed);
+            checker.extend(bytes.iter().copied());
+            assert!(checker.is_palindrome(), "{len} bytes, seed {seed}");
+
+            // a single changed byte breaks it
+            if len > 1 {
+                bytes[0] = bytes[0].wrapping_add(1);
+                let mut checker = StreamChecker::with_seed(seed);
+                checker.extend(bytes);
+                assert!(!checker.is_palindrome(), "{len} bytes, seed {seed}");
+            }
+        }
+    }
+    assert_eq!(StreamChecker::with_seed(5), StreamChecker::with_seed(5));
+    assert_ne!(StreamChecker::with_seed(5), StreamChecker::with_seed(6));
+}
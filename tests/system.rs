@@ -0,0 +1,130 @@
+use palindrome_products::{BalancedTernary, DigitSystem, FixedWidth, Grouped, Palindrome, Radix};
+
+/// Balanced ternary digits by brute force: the value of each digit string, counting up.
+fn balanced_ternary_value(digits: &[i8]) -> i64 {
+    digits.iter().fold(0, |value, &d| value * 3 + d as i64)
+}
+
+#[test]
+/// the decimal system agrees with the base ten check
+fn test_decimal() {
+    for value in 0..100_000 {
+        let expected = Palindrome::new(value).map(u64::from);
+        assert_eq!(Palindrome::new_with(&Radix::DECIMAL, value), expected, "{value}");
+    }
+    let largest = 18446744066044764481;
+    assert_eq!(Palindrome::new_with(&Radix::new(10), largest), Some(largest));
+}
+
+#[test]
+/// other radixes agree with parsing in that radix
+fn test_radix() {
+    for base in [2, 3, 16, 36] {
+        for value in 0..10_000u64 {
+            let digits = Radix::new(base).digits(value);
+            let written: String = digits.iter().map(|&d| char::from_digit(d as u32, base).unwrap()).collect();
+            let expected = Palindrome::value_from_str_radix(&written, base).ok();
+            assert_eq!(Palindrome::new_with(&Radix::new(base), value), expected, "{value} in base {base}");
+        }
+    }
+    assert_eq!(Radix::new(256).digits(0x0102), [1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "base 1 is not within 2..=256")]
+/// a radix below two is rejected
+fn test_radix_too_small() {
+    Radix::new(1);
+}
+
+#[test]
+/// balanced ternary digits spell out the value and only use -1, 0 and 1
+fn test_balanced_ternary() {
+    assert_eq!(BalancedTernary.digits(0), [0]);
+    assert_eq!(BalancedTernary.digits(2), [1, -1]);
+    assert_eq!(BalancedTernary.digits(8), [1, 0, -1]);
+    for value in [1, 5, 100, 12345, u32::MAX as u64, u64::MAX] {
+        let digits = BalancedTernary.digits(value);
+        assert_eq!(digits[0], 1, "{value}");
+        assert!(digits.iter().all(|d| (-1..=1).contains(d)), "{value}");
+        if value < 1 << 62 {
+            assert_eq!(balanced_ternary_value(&digits), value as i64);
+        }
+    }
+
+    // 2 is 1- (3 - 1), 4 is 11, 79 is 100-1 (81 - 3 + 1) and 91 is 10101
+    assert_eq!(Palindrome::new_with(&BalancedTernary, 2), None);
+    assert_eq!(Palindrome::new_with(&BalancedTernary, 4), Some(4));
+    assert_eq!(Palindrome::new_with(&BalancedTernary, 79), None);
+    assert_eq!(Palindrome::new_with(&BalancedTernary, 91), Some(91));
+}
+
+#[test]
+/// groups of digits are compared whole, with the leading group padded
+fn test_grouped() {
+    let pairs = Grouped::new(Radix::DECIMAL, 2, 0);
+    assert_eq!(pairs.digits(12345), [vec![0, 1], vec![2, 3], vec![4, 5]]);
+    assert_eq!(Palindrome::new_with(&pairs, 123412), Some(123412));
+    assert_eq!(Palindrome::new_with(&pairs, 1221), None);
+    assert_eq!(Palindrome::new_with(&pairs, 1212), Some(1212));
+    // padding makes 10_01 out of 1001 but 01_00_01 out of 10001
+    assert_eq!(Palindrome::new_with(&pairs, 10001), Some(10001));
+    assert_eq!(Palindrome::new_with(&pairs, 7), Some(7));
+
+    // groups of one are the digits themselves
+    let singles = Grouped::new(BalancedTernary, 1, 0);
+    for value in 0..1000 {
+        assert_eq!(singles.is_palindrome(value), BalancedTernary.is_palindrome(value), "{value}");
+    }
+}
+
+#[test]
+/// leading zeros pad every value to the width, and values which do not fit are not palindromes
+fn test_fixed_width() {
+    let three = FixedWidth::zero_padded(3);
+    assert_eq!(three.digits(10), [0, 1, 0]);
+    assert_eq!(Palindrome::new_with(&three, 10), Some(10));
+    assert_eq!(Palindrome::new_with(&three, 0), Some(0));
+    assert_eq!(Palindrome::new_with(&three, 11), None);
+    assert_eq!(Palindrome::new_with(&three, 121), Some(121));
+    assert_eq!(Palindrome::new_with(&three, 1001), None);
+    let found: Vec<u64> = (0..1000).filter(|&v| three.is_palindrome(v)).collect();
+    assert_eq!(found.len(), 100);
+    assert!(found.iter().all(|v| v % 10 == v / 100));
+
+    // other systems and padding digits
+    let binary = FixedWidth::new(Radix::new(2), 4, 1);
+    assert_eq!(Palindrome::new_with(&binary, 0b11), Some(0b11));
+    assert_eq!(Palindrome::new_with(&binary, 0b10), None);
+    assert_eq!(Palindrome::new_with(&FixedWidth::zero_padded(0), 7), None);
+}
+
+#[test]
+/// comparison and normalization can be overridden
+fn test_custom_system() {
+    /// Binary with trailing zeros dropped, so that 0b1100 reads like 0b11.
+    struct Trimmed;
+
+    impl DigitSystem for Trimmed {
+        type Digit = u8;
+
+        fn digits(&self, value: u64) -> Vec<u8> {
+            Radix::new(2).digits(value)
+        }
+
+        fn normalize(&self, digits: &mut Vec<u8>) {
+            while digits.len() > 1 && digits.last() == Some(&0) {
+                digits.pop();
+            }
+        }
+    }
+
+    assert_eq!(Palindrome::new_with(&Trimmed, 0b1100), Some(0b1100));
+    assert_eq!(Palindrome::new_with(&Trimmed, 0b1010), Some(0b1010));
+    assert_eq!(Palindrome::new_with(&Trimmed, 0b1101), None);
+    assert_eq!(Palindrome::new_with(&Trimmed, 0), Some(0));
+
+    // a trait object works as well
+    let system: &dyn DigitSystem<Digit = u8> = &Trimmed;
+    assert_eq!(Palindrome::new_with(system, 0b110), Some(0b110));
+}
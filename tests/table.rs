@@ -0,0 +1,58 @@
+use std::io::ErrorKind;
+
+use palindrome_products::{Palindrome, PalindromeSet};
+
+#[test]
+/// generated tables hold exactly the palindromes below the bound
+fn test_generate_tables() {
+    let below = PalindromeSet::below(1000);
+    assert_eq!(below.len(), 10 + 9 + 90);
+    assert!(below.iter().all(|p| p.into_inner() < 1000));
+    assert_eq!(PalindromeSet::up_to_digits(3), below);
+    assert_eq!(PalindromeSet::up_to_digits(0).len(), 0);
+}
+
+#[test]
+/// a table survives a round trip through its binary format
+fn test_table_round_trip() {
+    let mut set = PalindromeSet::below(100_000);
+    set.insert(Palindrome::new(18446744066044764481).expect("is a palindrome"));
+
+    let mut bytes = Vec::new();
+    set.write_to(&mut bytes).expect("write to memory");
+    assert_eq!(bytes.len(), 8 + 200 * 8 + 4 * set.len());
+    assert_eq!(PalindromeSet::read_from(bytes.as_slice()).expect("valid table"), set);
+}
+
+#[test]
+/// a table can be saved to and loaded from a file
+fn test_table_save_and_load() {
+    let path = std::env::temp_dir().join(format!("palindrome-table-{}.bin", std::process::id()));
+    let set = PalindromeSet::up_to_digits(5);
+    set.save(&path).expect("save table");
+    let loaded = PalindromeSet::load(&path);
+    std::fs::remove_file(&path).expect("remove table");
+    assert_eq!(loaded.expect("load table"), set);
+}
+
+#[test]
+/// malformed tables are rejected as invalid data
+fn test_table_rejects_malformed_input() {
+    let mut bytes = Vec::new();
+    PalindromeSet::below(1000).write_to(&mut bytes).expect("write to memory");
+
+    let mut bad_magic = bytes.clone();
+    bad_magic[0] = b'X';
+    let mut unsorted = bytes.clone();
+    let last = unsorted.len() - 4;
+    unsorted[last..].copy_from_slice(&0u32.to_le_bytes());
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+
+    for input in [bad_magic, unsorted, trailing] {
+        let err = PalindromeSet::read_from(input.as_slice()).expect_err("malformed table");
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+    let err = PalindromeSet::read_from(&bytes[..bytes.len() - 1]).expect_err("truncated table");
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+}
@@ -0,0 +1,209 @@
+use palindrome_products::text::{
+    can_form_palindrome, count_palindromic_substrings, longest_palindromic_prefix, longest_palindromic_substring,
+    longest_palindromic_suffix, min_palindrome_cuts, min_palindrome_partition, normalize, palindrome_pairs,
+    palindromic_permutation, shortest_palindrome, Eertree,
+};
+use palindrome_products::Palindromic;
+
+#[test]
+/// strings with at most one odd character count can be rearranged
+fn test_can_form_palindrome() {
+    for s in ["", "a", "aab", "carerac", "aabbccd"] {
+        assert!(can_form_palindrome(s), "{s}");
+    }
+    for s in ["ab", "abc", "aabbcd"] {
+        assert!(!can_form_palindrome(s), "{s}");
+    }
+}
+
+#[test]
+/// normalization ignores case and punctuation
+fn test_can_form_palindrome_normalized() {
+    assert!(!can_form_palindrome("Taco, cat!"));
+    assert!(can_form_palindrome(&normalize("Taco, cat!")));
+}
+
+#[test]
+/// the constructed permutation is a palindrome made of the same characters
+fn test_palindromic_permutation() {
+    assert_eq!(palindromic_permutation("aabbc").as_deref(), Some("abcba"));
+    assert_eq!(palindromic_permutation("carerac").as_deref(), Some("acrerca"));
+    assert_eq!(palindromic_permutation("").as_deref(), Some(""));
+    assert_eq!(palindromic_permutation("abc"), None);
+}
+
+/// Every string over a small alphabet up to `len` characters.
+fn all_strings(alphabet: &[char], len: usize) -> Vec<String> {
+    let mut strings = vec![String::new()];
+    let mut last = vec![String::new()];
+    for _ in 0..len {
+        last = last.iter().flat_map(|s| alphabet.iter().map(move |&c| format!("{s}{c}"))).collect();
+        strings.extend(last.iter().cloned());
+    }
+    strings
+}
+
+#[test]
+/// the longest palindromic prefix and suffix agree with trying every length
+fn test_longest_palindromic_prefix_and_suffix() {
+    for s in all_strings(&['a', 'b', 'é'], 7) {
+        let chars: Vec<char> = s.chars().collect();
+        let is_palindrome = |part: &[char]| part.iter().eq(part.iter().rev());
+        let prefix = (0..=chars.len()).rev().find(|&n| is_palindrome(&chars[..n])).unwrap();
+        let suffix = (0..=chars.len()).rev().find(|&n| is_palindrome(&chars[chars.len() - n..])).unwrap();
+        assert_eq!(longest_palindromic_prefix(&s).chars().count(), prefix, "{s}");
+        assert_eq!(longest_palindromic_suffix(&s).chars().count(), suffix, "{s}");
+        assert!(s.starts_with(longest_palindromic_prefix(&s)) && s.ends_with(longest_palindromic_suffix(&s)));
+    }
+
+    assert_eq!(longest_palindromic_prefix("abacus"), "aba");
+    assert_eq!(longest_palindromic_suffix("banana"), "anana");
+    assert_eq!(longest_palindromic_prefix(""), "");
+    assert_eq!(longest_palindromic_suffix("日本日"), "日本日");
+}
+
+#[test]
+/// prepending to make a palindrome adds as few characters as possible
+fn test_shortest_palindrome() {
+    assert_eq!(shortest_palindrome("abcd"), "dcbabcd");
+    assert_eq!(shortest_palindrome("aacecaaa"), "aaacecaaa");
+    assert_eq!(shortest_palindrome("racecar"), "racecar");
+    assert_eq!(shortest_palindrome(""), "");
+    for s in all_strings(&['x', 'y'], 8) {
+        let shortest = shortest_palindrome(&s);
+        assert!(shortest.is_palindrome() && shortest.ends_with(&s), "{s}");
+        assert_eq!(shortest.len() - s.len(), s.len() - longest_palindromic_prefix(&s).len(), "{s}");
+    }
+}
+
+#[test]
+/// palindrome pairs agree with testing every concatenation
+fn test_palindrome_pairs() {
+    assert_eq!(palindrome_pairs(&["abcd", "dcba", "lls", "s", "sssll"]), [(0, 1), (1, 0), (2, 4), (3, 2)]);
+    assert_eq!(palindrome_pairs(&["a", ""]), [(0, 1), (1, 0)]);
+    assert_eq!(palindrome_pairs(&[]), []);
+
+    let strings = all_strings(&['a', 'b'], 4);
+    for window in strings.chunks(9).chain([&strings[..], &["ab", "ab", "ba", "", ""].map(String::from)[..]]) {
+        let words: Vec<&str> = window.iter().map(String::as_str).collect();
+        let mut expected = Vec::new();
+        for (i, a) in words.iter().enumerate() {
+            for (j, b) in words.iter().enumerate() {
+                if i != j && format!("{a}{b}").is_palindrome() {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert_eq!(palindrome_pairs(&words), expected, "{words:?}");
+    }
+}
+
+#[test]
+/// palindromic substrings are counted at every position and agree with testing every substring
+fn test_count_palindromic_substrings() {
+    assert_eq!(count_palindromic_substrings("aaa"), 6);
+    assert_eq!(count_palindromic_substrings("abc"), 3);
+    assert_eq!(count_palindromic_substrings(""), 0);
+    for s in all_strings(&['a', 'b', 'ö'], 6) {
+        let chars: Vec<char> = s.chars().collect();
+        let substrings = (0..chars.len()).flat_map(|i| (i + 1..=chars.len()).map(move |j| (i, j)));
+        let expected = substrings.filter(|&(i, j)| chars[i..j].iter().eq(chars[i..j].iter().rev())).count();
+        assert_eq!(count_palindromic_substrings(&s), expected, "{s}");
+    }
+}
+
+#[test]
+/// the longest palindromic substring is the first of the longest found by testing every substring
+fn test_longest_palindromic_substring() {
+    assert_eq!(longest_palindromic_substring("babad"), "bab");
+    assert_eq!(longest_palindromic_substring("cbbd"), "bb");
+    assert_eq!(longest_palindromic_substring("xracecary"), "racecar");
+    assert_eq!(longest_palindromic_substring(""), "");
+    for s in all_strings(&['a', 'b', 'ö'], 6) {
+        let chars: Vec<char> = s.chars().collect();
+        let mut expected = (0, 0);
+        for i in 0..chars.len() {
+            for j in i + 1..=chars.len() {
+                if j - i > expected.1 - expected.0 && chars[i..j].iter().eq(chars[i..j].iter().rev()) {
+                    expected = (i, j);
+                }
+            }
+        }
+        let expected: String = chars[expected.0..expected.1].iter().collect();
+        assert_eq!(longest_palindromic_substring(&s), expected, "{s}");
+    }
+}
+
+/// The fewest palindromes which `chars` splits into, trying every first part.
+fn fewest_parts(chars: &[char]) -> usize {
+    (1..=chars.len())
+        .filter(|&len| chars[..len].iter().eq(chars[..len].iter().rev()))
+        .map(|len| 1 + fewest_parts(&chars[len..]))
+        .min()
+        .unwrap_or(0)
+}
+
+#[test]
+/// the fewest cuts agree with trying every split, and the partition is made of that many palindromes
+fn test_min_palindrome_cuts() {
+    assert_eq!(min_palindrome_cuts("aab"), 1);
+    assert_eq!(min_palindrome_partition("aab"), ["aa", "b"]);
+    assert_eq!(min_palindrome_cuts("racecar"), 0);
+    assert_eq!(min_palindrome_cuts("abcde"), 4);
+    assert_eq!(min_palindrome_cuts(""), 0);
+    assert_eq!(min_palindrome_partition(""), Vec::<&str>::new());
+    for s in all_strings(&['a', 'b', 'ñ'], 6) {
+        let parts = fewest_parts(&s.chars().collect::<Vec<_>>());
+        assert_eq!(min_palindrome_cuts(&s), parts.saturating_sub(1), "{s}");
+        let partition = min_palindrome_partition(&s);
+        assert_eq!(partition.len(), parts, "{s}");
+        assert_eq!(partition.concat(), s);
+        assert!(partition.iter().all(|part| !part.is_empty() && part.is_palindrome()), "{s}");
+    }
+}
+
+#[test]
+/// the eertree holds every distinct palindrome with its occurrences, agreeing with testing every substring
+fn test_eertree() {
+    for s in all_strings(&['a', 'b', 'ü'], 6) {
+        let chars: Vec<char> = s.chars().collect();
+        let mut expected = std::collections::BTreeMap::new();
+        for i in 0..chars.len() {
+            for j in i + 1..=chars.len() {
+                if chars[i..j].iter().eq(chars[i..j].iter().rev()) {
+                    *expected.entry(chars[i..j].iter().collect::<String>()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let tree: Eertree = s.chars().collect();
+        assert_eq!(tree.distinct_count(), expected.len(), "{s}");
+        let palindromes: std::collections::BTreeMap<String, usize> = tree.palindromes().into_iter().collect();
+        assert_eq!(palindromes, expected, "{s}");
+        for (palindrome, &count) in &expected {
+            assert_eq!(tree.occurrences(palindrome), count, "{palindrome} in {s}");
+        }
+        assert_eq!(expected.values().sum::<usize>(), count_palindromic_substrings(&s));
+    }
+}
+
+#[test]
+/// appending reports new palindromes as they appear, in order
+fn test_eertree_online() {
+    let mut tree = Eertree::new();
+    assert_eq!(tree.distinct_count(), 0);
+    assert_eq!("abaab".chars().map(|c| tree.push(c)).collect::<Vec<_>>(), [true, true, true, true, true]);
+    let palindromes: Vec<String> = tree.palindromes().into_iter().map(|(p, _)| p).collect();
+    assert_eq!(palindromes, ["a", "b", "aba", "aa", "baab"]);
+    // the longest palindromic suffix of abaabc is c, which is new, and of abaabca only a, which is not
+    assert!(tree.push('c'));
+    assert!(!tree.push('a'));
+
+    tree.extend("a".chars());
+    assert_eq!(tree.occurrences("a"), 5);
+    assert_eq!(tree.occurrences("aa"), 2);
+    assert_eq!(tree.occurrences("ab"), 0);
+    assert_eq!(tree.occurrences("bb"), 0);
+    assert_eq!(tree.occurrences(""), 0);
+    assert_eq!(Eertree::default().palindromes(), []);
+}
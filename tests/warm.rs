@@ -0,0 +1,46 @@
+use palindrome_products::{
+    is_warmed_up, palindrome_products_with, warm_up, warmed_up_digits, Palindrome, Search, Strategy,
+};
+
+/// The palindromes within some ranges in and around the six digit numbers.
+fn sample() -> Vec<Vec<u64>> {
+    [0..=0, 0..=999_999, 10..=20, 12_345..=678_900, 999_999..=1_000_001, 900..=1_100]
+        .into_iter()
+        .map(|range| Palindrome::in_range(range).map(Palindrome::into_inner).collect())
+        .collect()
+}
+
+fn search_largest() -> Vec<Option<Palindrome>> {
+    [(10, 99), (100, 999), (15, 15)]
+        .into_iter()
+        .map(|(min, max)| Search::new(min, max).run().unwrap().largest())
+        .collect()
+}
+
+#[test]
+/// warming up fills a shared table which serves ranges without changing any result
+fn test_warm_up() {
+    // the table is shared by the whole process, so everything runs in one test
+    let (cold, searches) = (sample(), search_largest());
+
+    warm_up(0);
+    assert!(!is_warmed_up());
+    warm_up(6);
+    assert!(is_warmed_up());
+    assert_eq!(warmed_up_digits(), 6);
+    warm_up(4);
+    assert_eq!(warmed_up_digits(), 6);
+
+    assert_eq!(sample(), cold);
+    for value in (0..=1_100_000).chain([u64::MAX, 18446744066044764481]) {
+        assert_eq!(Palindrome::new(value), Palindrome::new_const(value), "{value}");
+    }
+    assert_eq!(search_largest(), searches);
+    let rev: Vec<u64> = Palindrome::in_range(100..=200).rev().map(Palindrome::into_inner).collect();
+    assert_eq!(rev, [191, 181, 171, 161, 151, 141, 131, 121, 111, 101]);
+    assert_eq!(Palindrome::in_range(0..=999_999).count(), 1999);
+    assert_eq!(
+        palindrome_products_with(100, 999, Strategy::PalindromeFirst),
+        palindrome_products_with(100, 999, Strategy::ProductFirst)
+    );
+}
@@ -0,0 +1,44 @@
+use palindrome_products::{Palindrome, Search, Witness};
+
+fn palindrome(v: u64) -> Palindrome {
+    Palindrome::new(v).expect("is a palindrome")
+}
+
+#[test]
+/// the witness of a search holds the extremes with their factor pairs and verifies
+fn test_search_witness() {
+    let witness = Search::new(10, 99).witness().unwrap();
+    assert_eq!(witness.smallest(), Some(&(palindrome(121), vec![(11, 11)])));
+    assert_eq!(witness.largest(), Some(&(palindrome(9009), vec![(91, 99)])));
+    assert!(witness.verify(10, 99));
+
+    let empty = Search::new(15, 15).witness().unwrap();
+    assert_eq!(empty, Witness::new(None, None));
+    assert!(empty.verify(15, 15));
+}
+
+#[test]
+/// tampered or foreign claims do not verify
+fn test_witness_rejects_bad_claims() {
+    let witness = Search::new(10, 99).witness().unwrap();
+    assert!(!witness.verify(92, 99), "factor outside the range");
+
+    let wrong_product = Witness::new(Some((palindrome(121), vec![(11, 12)])), Some((palindrome(9009), vec![(91, 99)])));
+    assert!(!wrong_product.verify(10, 99));
+    let no_pairs = Witness::new(Some((palindrome(121), vec![])), Some((palindrome(121), vec![(11, 11)])));
+    assert!(!no_pairs.verify(10, 99));
+    let one_sided = Witness::new(Some((palindrome(121), vec![(11, 11)])), None);
+    assert!(!one_sided.verify(10, 99));
+    let unordered = Witness::new(Some((palindrome(9009), vec![(91, 99)])), Some((palindrome(121), vec![(11, 11)])));
+    assert!(!unordered.verify(10, 99));
+    let overflowing = Witness::new(Some((palindrome(1), vec![(u64::MAX, 2)])), Some((palindrome(1), vec![(1, 1)])));
+    assert!(!overflowing.verify(0, u64::MAX));
+}
+
+#[test]
+/// witnesses of constrained searches only list accepted factor pairs
+fn test_constrained_witness() {
+    let witness = Search::new(10, 99).exclude_factors(&[91]).witness().unwrap();
+    assert!(witness.verify(10, 99));
+    assert!(witness.largest().is_some_and(|(_, pairs)| pairs.iter().all(|&(a, b)| a != 91 && b != 91)));
+}